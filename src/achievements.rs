@@ -0,0 +1,155 @@
+use std::{collections::VecDeque, fmt::Write};
+
+use crate::storage;
+
+/// File the unlocked achievements are saved to
+pub const ACHIEVEMENTS_FILE: &str = "achievements.txt";
+
+/// How long the toast of an unlocked achievement stays on screen, in seconds
+const TOAST_TIME: f64 = 4.;
+
+/// Days to survive for the door and meth achievements, done once the next day
+/// starts
+pub const OPEN_DOOR_DAYS: u32 = 10;
+pub const SOBER_DAYS: u32 = 5;
+/// Employees to have at once in the company
+pub const FULL_OFFICE_HEADCOUNT: usize = 16;
+/// QTEs to refuse in a run without accepting any
+pub const ALWAYS_NO_ANSWERS: u32 = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Achievement {
+    OpenDoor,
+    Sober,
+    FullOffice,
+    AlwaysNo,
+    SafeEvacuation,
+}
+
+impl Achievement {
+    pub const ALL: [Self; 5] = [
+        Self::OpenDoor,
+        Self::Sober,
+        Self::FullOffice,
+        Self::AlwaysNo,
+        Self::SafeEvacuation,
+    ];
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::OpenDoor => "Portes ouvertes",
+            Self::Sober => "Sobriété",
+            Self::FullOffice => "Open space",
+            Self::AlwaysNo => "Le roi du non",
+            Self::SafeEvacuation => "Exercice incendie",
+        }
+    }
+
+    pub fn get_description(self) -> String {
+        match self {
+            Self::OpenDoor => {
+                format!("Survivre {OPEN_DOOR_DAYS} jours sans jamais fermer la porte")
+            }
+            Self::Sober => format!("Survivre {SOBER_DAYS} jours sans méth"),
+            Self::FullOffice => {
+                format!("Avoir {FULL_OFFICE_HEADCOUNT} employés en même temps")
+            }
+            Self::AlwaysNo => format!(
+                "Répondre non à {ALWAYS_NO_ANSWERS} QTE dans une partie, sans jamais dire oui"
+            ),
+            Self::SafeEvacuation => "Passer une alarme incendie sans perdre personne".to_owned(),
+        }
+    }
+
+    /// Name in the saved file
+    const fn get_key(self) -> &'static str {
+        match self {
+            Self::OpenDoor => "open_door",
+            Self::Sober => "sober",
+            Self::FullOffice => "full_office",
+            Self::AlwaysNo => "always_no",
+            Self::SafeEvacuation => "safe_evacuation",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.get_key() == key)
+    }
+}
+
+/// Achievements unlocked across runs, and the toasts of the new ones
+#[derive(Default)]
+pub struct Achievements {
+    unlocked: Vec<Achievement>,
+    toasts: VecDeque<Achievement>,
+    /// When the first toast of the queue appeared
+    toast_start: Option<f64>,
+}
+
+impl Achievements {
+    /// Achievements saved in `ACHIEVEMENTS_FILE`. Unknown lines are skipped so
+    /// that removed achievements do not lock the others.
+    pub fn load() -> Self {
+        let mut achievements = Self::default();
+
+        if let Some(text) = storage::read(ACHIEVEMENTS_FILE) {
+            achievements.unlocked = text
+                .lines()
+                .filter_map(|line| line.strip_prefix("unlocked "))
+                .filter_map(|key| Achievement::from_key(key.trim()))
+                .collect();
+        }
+
+        achievements
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut text = String::new();
+        for a in &self.unlocked {
+            writeln!(text, "unlocked {}", a.get_key()).unwrap();
+        }
+
+        storage::write(ACHIEVEMENTS_FILE, &text)
+    }
+
+    /// Unlocks the achievement and shows its toast, the first time only
+    pub fn unlock(&mut self, achievement: Achievement) {
+        if self.is_unlocked(achievement) {
+            return;
+        }
+
+        self.unlocked.push(achievement);
+        self.toasts.push_back(achievement);
+
+        if let Err(error) = self.save() {
+            println!("Enregistrement des succès impossible : {error}");
+        }
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    pub const fn unlocked_count(&self) -> usize {
+        self.unlocked.len()
+    }
+
+    /// Moves on to the next toast once the current one was shown long enough
+    pub fn update_toasts(&mut self, time: f64) {
+        let start = *self.toast_start.get_or_insert(time);
+
+        if time - start > TOAST_TIME {
+            self.toasts.pop_front();
+            self.toast_start = Some(time);
+        }
+
+        if self.toasts.is_empty() {
+            self.toast_start = None;
+        }
+    }
+
+    /// Achievement whose toast is on screen
+    pub fn get_toast(&self) -> Option<Achievement> {
+        self.toasts.front().copied()
+    }
+}
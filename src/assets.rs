@@ -1,23 +1,161 @@
+use std::sync::OnceLock;
+
 use lazy_static::lazy_static;
 use macroquad::{
+    audio::{load_sound_from_bytes, play_sound_once, Sound},
+    color::Color,
+    math::vec3,
     prelude::ImageFormat,
     text::{load_ttf_font_from_bytes, Font},
-    texture::Texture2D,
+    texture::{Image, Texture2D},
 };
 
+/// Reference colors of the parts of `employee0_normal.png`, used to split it in layers
+const EMPLOYEE_LAYER_COLORS: [Color; 4] = [
+    // Shirt
+    Color::new(0.88, 0.45, 0.43, 1.),
+    // Skin
+    Color::new(0.85, 0.74, 0.55, 1.),
+    // Hair
+    Color::new(0.35, 0.15, 0.0, 1.),
+    // Eyes
+    Color::new(0.1, 0.1, 0.1, 1.),
+];
+
+#[derive(Clone, Copy)]
+enum EmployeeLayer {
+    Shirt,
+    Skin,
+    Hair,
+    Eyes,
+}
+
+/// Extracts one part of the employee sprite as a white mask that keeps the original shading,
+/// so that it can be tinted when drawn.
+fn employee_layer(layer: EmployeeLayer) -> Texture2D {
+    let mut image = Image::from_file_with_format(
+        include_bytes!("../assets/employees/employee0_normal.png"),
+        Some(ImageFormat::Png),
+    )
+    .unwrap();
+
+    let distance = |a: Color, b: Color| vec3(a.r - b.r, a.g - b.g, a.b - b.b).length_squared();
+
+    for y in 0..image.height() as u32 {
+        for x in 0..image.width() as u32 {
+            let pixel = image.get_pixel(x, y);
+
+            let closest = (0..EMPLOYEE_LAYER_COLORS.len())
+                .min_by(|&a, &b| {
+                    distance(pixel, EMPLOYEE_LAYER_COLORS[a])
+                        .total_cmp(&distance(pixel, EMPLOYEE_LAYER_COLORS[b]))
+                })
+                .unwrap();
+
+            let new_pixel = if closest != layer as usize || pixel.a == 0. {
+                Color::new(0., 0., 0., 0.)
+            } else if matches!(layer, EmployeeLayer::Eyes) {
+                pixel
+            } else {
+                let reference = EMPLOYEE_LAYER_COLORS[closest];
+                let shade = ((pixel.r + pixel.g + pixel.b)
+                    / (reference.r + reference.g + reference.b))
+                    .clamp(0., 1.);
+                Color::new(shade, shade, shade, pixel.a)
+            };
+
+            image.set_pixel(x, y, new_pixel);
+        }
+    }
+
+    Texture2D::from_image(&image)
+}
+
+/// Door with a jagged crack through it, drawn once the employees broke it
+fn broken_door() -> Texture2D {
+    let mut image = Image::from_file_with_format(
+        include_bytes!("../assets/office/door.png"),
+        Some(ImageFormat::Png),
+    )
+    .unwrap();
+
+    let width = image.width() as u32;
+    let height = image.height() as u32;
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut pixel = image.get_pixel(x, y);
+
+            let crack = 0.06f32.mul_add((x as f32 / width as f32 * 14.).sin(), 0.55);
+            if (y as f32 / height as f32 - crack).abs() < 0.04 {
+                pixel.a = 0.;
+            } else {
+                pixel.r *= 0.7;
+                pixel.g *= 0.7;
+                pixel.b *= 0.7;
+            }
+
+            image.set_pixel(x, y, pixel);
+        }
+    }
+
+    Texture2D::from_image(&image)
+}
+
+/// Desks of `office.png`, with their shadow
+const OFFICE_DESKS: [(u32, u32); 4] = [(480, 145), (775, 145), (480, 425), (775, 425)];
+const DESK_SPRITE_SIZE: (u32, u32) = (145, 170);
+
+/// Office without its desks, so that they can be drawn where the layout puts them.
+/// The floor stripes are vertical, so the desks are covered with the floor of the
+/// lane between them, at the same x.
+fn office_floor() -> Texture2D {
+    let mut image = Image::from_file_with_format(
+        include_bytes!("../assets/office/office.png"),
+        Some(ImageFormat::Png),
+    )
+    .unwrap();
+
+    for (desk_x, desk_y) in OFFICE_DESKS {
+        for y in desk_y..desk_y + DESK_SPRITE_SIZE.1 {
+            for x in desk_x..desk_x + DESK_SPRITE_SIZE.0 {
+                let floor = image.get_pixel(x, 320 + y % 95);
+                image.set_pixel(x, y, floor);
+            }
+        }
+    }
+
+    Texture2D::from_image(&image)
+}
+
+/// First desk of `office.png`, with its shadow
+fn desk() -> Texture2D {
+    let image = Image::from_file_with_format(
+        include_bytes!("../assets/office/office.png"),
+        Some(ImageFormat::Png),
+    )
+    .unwrap();
+
+    let desk = image.sub_image(macroquad::math::Rect::new(485., 150., 135., 158.));
+
+    Texture2D::from_image(&desk)
+}
+
 lazy_static! {
     pub static ref EMPLOYEE_TEXTURE: Texture2D = Texture2D::from_file_with_format(
         include_bytes!("../assets/employees/employee0_normal.png"),
         Some(ImageFormat::Png),
     );
+    pub static ref EMPLOYEE_SHIRT_LAYER: Texture2D = employee_layer(EmployeeLayer::Shirt);
+    pub static ref EMPLOYEE_SKIN_LAYER: Texture2D = employee_layer(EmployeeLayer::Skin);
+    pub static ref EMPLOYEE_HAIR_LAYER: Texture2D = employee_layer(EmployeeLayer::Hair);
+    pub static ref EMPLOYEE_EYES_LAYER: Texture2D = employee_layer(EmployeeLayer::Eyes);
     pub static ref MANAGER_TEXTURE: Texture2D = Texture2D::from_file_with_format(
         include_bytes!("../assets/employees/manager.png"),
         Some(ImageFormat::Png),
     );
-    pub static ref OFFICE_TEXTURE: Texture2D = Texture2D::from_file_with_format(
-        include_bytes!("../assets/office/office.png"),
-        Some(ImageFormat::Png),
-    );
+    pub static ref OFFICE_FLOOR_TEXTURE: Texture2D = office_floor();
+    pub static ref DESK_TEXTURE: Texture2D = desk();
     pub static ref FRAME_TEXTURE: Texture2D = Texture2D::from_file_with_format(
         include_bytes!("../assets/gui/qte_frame.png"),
         Some(ImageFormat::Png),
@@ -72,6 +210,7 @@ lazy_static! {
         include_bytes!("../assets/office/door.png"),
         Some(ImageFormat::Png),
     );
+    pub static ref DOOR_BROKEN_TEXTURE: Texture2D = broken_door();
     pub static ref WINDOW_TEXTURE: Texture2D = Texture2D::from_file_with_format(
         include_bytes!("../assets/office/window.png"),
         Some(ImageFormat::Png),
@@ -133,3 +272,22 @@ lazy_static! {
         Some(ImageFormat::Png),
     );
 }
+
+/// Sounds are loaded asynchronously, once before the game starts
+pub static DOOR_BREAK_SOUND: OnceLock<Sound> = OnceLock::new();
+
+/// A sound that can not be loaded is left empty, and never played
+pub async fn load_sounds() {
+    match load_sound_from_bytes(include_bytes!("../assets/sounds/destroy.wav")).await {
+        Ok(sound) => {
+            DOOR_BREAK_SOUND.get_or_init(|| sound);
+        }
+        Err(error) => println!("Chargement du son de la porte impossible : {error}"),
+    }
+}
+
+pub fn play(sound: &OnceLock<Sound>) {
+    if let Some(sound) = sound.get() {
+        play_sound_once(sound);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::qte::QteEffect;
+
+/// The first day of the game is a monday
+const WEEKDAYS: [&str; 7] = [
+    "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche",
+];
+
+/// Days of the week worked before the weekend
+const WORKDAYS: u32 = 5;
+
+const MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+/// A month of the game lasts a week, so that the seasons go by during a run
+const MONTH_DAYS: u32 = 7;
+
+/// The game starts with the school year, in september
+const FIRST_MONTH: u32 = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Spring => "Printemps",
+            Self::Summer => "Été",
+            Self::Autumn => "Automne",
+            Self::Winter => "Hiver",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Holiday {
+    NewYear,
+    LabourDay,
+    NationalDay,
+    Christmas,
+}
+
+/// Month and day of the month of each holiday
+const HOLIDAYS: [(u32, u32, Holiday); 4] = [
+    (0, 1, Holiday::NewYear),
+    (4, 1, Holiday::LabourDay),
+    (6, 2, Holiday::NationalDay),
+    (11, 4, Holiday::Christmas),
+];
+
+impl Holiday {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::NewYear => "Nouvel an",
+            Self::LabourDay => "Fête du travail",
+            Self::NationalDay => "Fête nationale",
+            Self::Christmas => "Noël",
+        }
+    }
+
+    /// What spending the holiday at the office does to the employees
+    pub const fn get_effect(self) -> QteEffect {
+        match self {
+            Self::NewYear => QteEffect::new(0., 0., 0., 0.2, 0., 0),
+            Self::LabourDay => QteEffect::new(-0.2, 0., 0., 0., 0., 0),
+            Self::NationalDay => QteEffect::new(0.1, 0., 0., 0.1, 0., 0),
+            Self::Christmas => QteEffect::new(-0.1, 0., 0., -0.2, 0., 0),
+        }
+    }
+}
+
+/// Days since the start of the game, from 0
+const fn elapsed(day: u32) -> u32 {
+    day.saturating_sub(1)
+}
+
+pub const fn get_weekday_name(day: u32) -> &'static str {
+    WEEKDAYS[(elapsed(day) % WEEKDAYS.len() as u32) as usize]
+}
+
+/// Saturday and sunday
+pub const fn is_weekend(day: u32) -> bool {
+    elapsed(day) % WEEKDAYS.len() as u32 >= WORKDAYS
+}
+
+/// Month of the year, from 0 for january
+pub const fn get_month(day: u32) -> u32 {
+    (FIRST_MONTH + elapsed(day) / MONTH_DAYS) % MONTHS.len() as u32
+}
+
+/// Day of the month, from 1. Not shown to the player: with months of a week,
+/// Christmas would fall on the 4th of december.
+const fn get_day_of_month(day: u32) -> u32 {
+    elapsed(day) % MONTH_DAYS + 1
+}
+
+pub const fn get_season(day: u32) -> Season {
+    match get_month(day) {
+        2..=4 => Season::Spring,
+        5..=7 => Season::Summer,
+        8..=10 => Season::Autumn,
+        _ => Season::Winter,
+    }
+}
+
+pub fn get_holiday(day: u32) -> Option<Holiday> {
+    let (month, day_of_month) = (get_month(day), get_day_of_month(day));
+
+    HOLIDAYS
+        .iter()
+        .find(|(m, d, _)| *m == month && *d == day_of_month)
+        .map(|(_, _, holiday)| *holiday)
+}
+
+/// "Jour 25 - Jeudi, décembre"
+pub fn describe_day(day: u32) -> String {
+    format!(
+        "Jour {} - {}, {}",
+        day,
+        get_weekday_name(day),
+        MONTHS[get_month(day) as usize]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weekend_comes_after_five_workdays() {
+        let weekend = (1..=14)
+            .filter(|&day| is_weekend(day))
+            .collect::<Vec<u32>>();
+
+        assert_eq!(weekend, vec![6, 7, 13, 14]);
+        assert_eq!(get_weekday_name(1), "Lundi");
+        assert_eq!(get_weekday_name(7), "Dimanche");
+        assert_eq!(get_weekday_name(8), "Lundi");
+    }
+
+    #[test]
+    fn months_last_a_week() {
+        assert_eq!((get_month(1), get_day_of_month(1)), (8, 1));
+        assert_eq!((get_month(7), get_day_of_month(7)), (8, 7));
+        assert_eq!((get_month(8), get_day_of_month(8)), (9, 1));
+    }
+
+    #[test]
+    fn year_rolls_over_after_december() {
+        assert_eq!(get_month(28), 11);
+        assert_eq!((get_month(29), get_day_of_month(29)), (0, 1));
+        assert_eq!(get_month(29 + 12 * MONTH_DAYS), 0);
+    }
+
+    #[test]
+    fn seasons_follow_the_months() {
+        assert_eq!(get_season(1), Season::Autumn);
+        assert_eq!(get_season(22), Season::Winter);
+        assert_eq!(get_season(43), Season::Spring);
+        assert_eq!(get_season(64), Season::Summer);
+    }
+
+    #[test]
+    fn holidays_fall_on_their_day() {
+        let holidays = (1..=12 * MONTH_DAYS)
+            .filter_map(|day| get_holiday(day).map(|holiday| (day, holiday)))
+            .collect::<Vec<(u32, Holiday)>>();
+
+        assert_eq!(
+            holidays,
+            vec![
+                (25, Holiday::Christmas),
+                (29, Holiday::NewYear),
+                (57, Holiday::LabourDay),
+                (72, Holiday::NationalDay),
+            ]
+        );
+    }
+
+    #[test]
+    fn day_description() {
+        assert_eq!(describe_day(25), "Jour 25 - Jeudi, décembre");
+        assert_eq!(describe_day(1), "Jour 1 - Lundi, septembre");
+    }
+}
@@ -0,0 +1,543 @@
+use std::fmt::Write;
+
+use macroquad::math::Vec2;
+
+use crate::{
+    company::Company,
+    layout::{DeskPlacement, Layout},
+    qte::QTE,
+    storage,
+};
+
+/// File the stars won in each level are saved to
+pub const CAMPAIGN_FILE: &str = "campaign.txt";
+
+/// Buttons of the office that a level can take away
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tool {
+    Meth,
+    Rh,
+    Window,
+    Policies,
+}
+
+impl Tool {
+    pub const ALL: [Self; 4] = [Self::Meth, Self::Rh, Self::Window, Self::Policies];
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Meth => "Méth",
+            Self::Rh => "RH",
+            Self::Window => "Fenêtre",
+            Self::Policies => "Règlement",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Objective {
+    /// Reach the day after the last one
+    Survive {
+        days: u32,
+    },
+    /// Money generated by the employees before the end of the last day
+    Earn {
+        money: f32,
+        days: u32,
+    },
+    Headcount {
+        count: usize,
+        days: u32,
+    },
+    /// Average hope of the employees, never under it until the end of the last day
+    KeepHope {
+        hope: f32,
+        days: u32,
+    },
+    /// Employees who died, fled, jumped or burned, never more until the end of
+    /// the last day
+    MaxLosses {
+        lost: usize,
+        days: u32,
+    },
+}
+
+/// What the objectives look at in the company
+#[derive(Clone, Copy, Debug, Default)]
+struct Progress {
+    day: u32,
+    earned: f32,
+    headcount: usize,
+    average_hope: Option<f32>,
+    /// Employees lost since the start of the game
+    lost: usize,
+}
+
+impl Progress {
+    fn of(company: &Company) -> Self {
+        Self {
+            day: company.get_day(),
+            earned: company.get_earned(),
+            headcount: company.employees_count(),
+            average_hope: company.average_hope(),
+            lost: company.lost_count(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ObjectiveStatus {
+    Pending,
+    Done,
+    Failed,
+}
+
+impl Objective {
+    pub fn get_description(&self) -> String {
+        match self {
+            Self::Survive { days } => format!("Survivre {days} jours"),
+            Self::Earn { money, days } => format!("Gagner {money} en {days} jours"),
+            Self::Headcount { count, days } => {
+                format!("Avoir {count} employés en {days} jours")
+            }
+            Self::KeepHope { hope, days } => {
+                format!("Garder l'espoir moyen au-dessus de {hope} pendant {days} jours")
+            }
+            Self::MaxLosses { lost, days } => {
+                format!("Perdre au plus {lost} employés en {days} jours")
+            }
+        }
+    }
+
+    const fn get_days(&self) -> u32 {
+        match self {
+            Self::Survive { days }
+            | Self::Earn { days, .. }
+            | Self::Headcount { days, .. }
+            | Self::KeepHope { days, .. }
+            | Self::MaxLosses { days, .. } => *days,
+        }
+    }
+
+    /// Where the objective stands now, `lost_before` are the employees lost
+    /// before the level started
+    fn check(&self, progress: &Progress, lost_before: usize) -> ObjectiveStatus {
+        let over = progress.day > self.get_days();
+
+        let (done, failed) = match *self {
+            Self::Survive { .. } => (over, false),
+            Self::Earn { money, .. } => (progress.earned >= money, over),
+            Self::Headcount { count, .. } => (progress.headcount >= count, over),
+            Self::KeepHope { hope, .. } => (over, progress.average_hope.is_some_and(|h| h < hope)),
+            Self::MaxLosses { lost, .. } => (over, progress.lost - lost_before > lost),
+        };
+
+        if failed {
+            ObjectiveStatus::Failed
+        } else if done {
+            ObjectiveStatus::Done
+        } else {
+            ObjectiveStatus::Pending
+        }
+    }
+
+    /// Status of an objective still pending when the level is won: the ones
+    /// to keep were kept, the ones to reach were not reached
+    const fn settle(&self) -> ObjectiveStatus {
+        match self {
+            Self::KeepHope { .. } | Self::MaxLosses { .. } => ObjectiveStatus::Done,
+            _ => ObjectiveStatus::Failed,
+        }
+    }
+}
+
+pub struct Level {
+    pub name: &'static str,
+    /// Said by the manager before the level starts
+    pub briefing: &'static str,
+    /// Top left corners of the desks, with the usual zones around them
+    desks: &'static [(f32, f32)],
+    /// Employees already at their desk, before the ones of the intro
+    pub staff: usize,
+    pub money: f32,
+    pub tools: &'static [Tool],
+    /// Keys of the random QTEs that can happen, any of them when empty
+    qtes: &'static [&'static str],
+    /// The first one wins the level, each of them is worth a star
+    pub objectives: [Objective; 3],
+}
+
+impl Level {
+    pub fn build_layout(&self) -> Layout {
+        Layout {
+            desks: self
+                .desks
+                .iter()
+                .map(|&(x, y)| DeskPlacement::new(Vec2::new(x, y)))
+                .collect(),
+            ..Layout::default()
+        }
+    }
+
+    pub fn allows_tool(&self, tool: Tool) -> bool {
+        self.tools.contains(&tool)
+    }
+
+    pub fn allows_qte(&self, qte: &QTE) -> bool {
+        self.qtes.is_empty() || qte.get_key().is_some_and(|key| self.qtes.contains(&key))
+    }
+}
+
+pub const LEVELS: [Level; 5] = [
+    Level {
+        name: "Premier jour",
+        briefing: "Bienvenue chez nous ! Deux bureaux, quelques développeurs, et un jeu à sortir. Pas de folies pour commencer.",
+        desks: &[(485., 150.), (485., 430.)],
+        staff: 2,
+        money: 1500.,
+        tools: &[Tool::Rh],
+        qtes: &["lunch", "intern", "mail"],
+        objectives: [
+            Objective::Survive { days: 3 },
+            Objective::Earn {
+                money: 1000.,
+                days: 3,
+            },
+            Objective::MaxLosses { lost: 0, days: 3 },
+        ],
+    },
+    Level {
+        name: "Recrutement",
+        briefing: "Les investisseurs veulent voir du monde dans les bureaux. Remplissez-moi cet open space, et ouvrez la fenêtre si ça sent le renfermé.",
+        desks: &[(485., 150.), (780., 150.), (485., 430.)],
+        staff: 2,
+        money: 2000.,
+        tools: &[Tool::Rh, Tool::Window],
+        qtes: &["lunch", "intern", "mail", "food", "child", "pizzas"],
+        objectives: [
+            Objective::Headcount { count: 6, days: 5 },
+            Objective::Earn {
+                money: 2500.,
+                days: 5,
+            },
+            Objective::KeepHope {
+                hope: 0.4,
+                days: 5,
+            },
+        ],
+    },
+    Level {
+        name: "La démo",
+        briefing: "La démo est dans une semaine et rien ne marche. J'ai trouvé un fournisseur pour un petit remontant, servez-vous avec modération.",
+        desks: &[(485., 150.), (780., 150.), (485., 430.), (780., 430.)],
+        staff: 3,
+        money: 2000.,
+        tools: &[Tool::Rh, Tool::Window, Tool::Meth],
+        qtes: &[
+            "lunch", "intern", "mail", "food", "child", "pizzas", "firewall", "nap", "guard",
+        ],
+        objectives: [
+            Objective::Earn {
+                money: 4000.,
+                days: 6,
+            },
+            Objective::MaxLosses { lost: 1, days: 6 },
+            Objective::KeepHope {
+                hope: 0.3,
+                days: 6,
+            },
+        ],
+    },
+    Level {
+        name: "L'audit",
+        briefing: "Un auditeur passe la semaine avec nous. Écrivez un règlement, gardez le sourire, et surtout que personne ne saute.",
+        desks: &[(485., 150.), (780., 150.), (485., 430.), (780., 430.)],
+        staff: 4,
+        money: 2500.,
+        tools: &[Tool::Rh, Tool::Window, Tool::Meth, Tool::Policies],
+        qtes: &[
+            "lunch", "intern", "mail", "food", "child", "pizzas", "firewall", "nap", "guard",
+            "boss", "union",
+        ],
+        objectives: [
+            Objective::Survive { days: 7 },
+            Objective::KeepHope {
+                hope: 0.5,
+                days: 7,
+            },
+            Objective::MaxLosses { lost: 0, days: 7 },
+        ],
+    },
+    Level {
+        name: "La release",
+        briefing: "C'est la dernière ligne droite. Tous les moyens sont bons, le big boss ne veut voir que les chiffres.",
+        desks: &[(485., 150.), (780., 150.), (485., 430.), (780., 430.)],
+        staff: 4,
+        money: 3000.,
+        tools: &Tool::ALL,
+        qtes: &[],
+        objectives: [
+            Objective::Earn {
+                money: 10000.,
+                days: 10,
+            },
+            Objective::Headcount { count: 12, days: 10 },
+            Objective::MaxLosses { lost: 2, days: 10 },
+        ],
+    },
+];
+
+/// The level being played, and where its objectives stand
+pub struct LevelRun {
+    index: usize,
+    statuses: [ObjectiveStatus; 3],
+    /// The intro costs an employee, it does not count against the objectives
+    lost_before: usize,
+}
+
+impl LevelRun {
+    pub const fn new(index: usize) -> Self {
+        Self {
+            index,
+            statuses: [ObjectiveStatus::Pending; 3],
+            lost_before: 0,
+        }
+    }
+
+    /// The intro is over, the objectives count from now
+    pub fn start(&mut self, company: &Company) {
+        self.lost_before = company.lost_count();
+    }
+
+    pub const fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub const fn get_level(&self) -> &'static Level {
+        &LEVELS[self.index]
+    }
+
+    pub const fn get_statuses(&self) -> &[ObjectiveStatus; 3] {
+        &self.statuses
+    }
+
+    /// Objectives are done or failed once and for all
+    pub fn update(&mut self, company: &Company) {
+        self.record(&Progress::of(company));
+    }
+
+    fn record(&mut self, progress: &Progress) {
+        let objectives = self.get_level().objectives;
+
+        for (status, objective) in self.statuses.iter_mut().zip(objectives) {
+            if *status == ObjectiveStatus::Pending {
+                *status = objective.check(progress, self.lost_before);
+            }
+        }
+    }
+
+    /// The first objective was done or failed
+    pub fn is_over(&self) -> bool {
+        self.statuses[0] != ObjectiveStatus::Pending
+    }
+
+    pub fn is_won(&self) -> bool {
+        self.statuses[0] == ObjectiveStatus::Done
+    }
+
+    /// Settles the pending objectives once the run is over, and returns the
+    /// stars won
+    pub fn finish(&mut self) -> usize {
+        let won = self.is_won();
+        let objectives = self.get_level().objectives;
+
+        for (status, objective) in self.statuses.iter_mut().zip(objectives) {
+            if *status == ObjectiveStatus::Pending {
+                *status = if won {
+                    objective.settle()
+                } else {
+                    ObjectiveStatus::Failed
+                };
+            }
+        }
+
+        self.get_stars()
+    }
+
+    pub fn get_stars(&self) -> usize {
+        if !self.is_won() {
+            return 0;
+        }
+
+        self.statuses
+            .iter()
+            .filter(|s| **s == ObjectiveStatus::Done)
+            .count()
+    }
+}
+
+/// Best stars of each level, a level is unlocked once the previous one is won
+pub struct Campaign {
+    stars: [usize; LEVELS.len()],
+}
+
+impl Campaign {
+    /// Stars saved in `CAMPAIGN_FILE`. Unknown lines are skipped so that
+    /// removed levels do not lock the others.
+    pub fn load() -> Self {
+        let mut campaign = Self {
+            stars: [0; LEVELS.len()],
+        };
+
+        if let Some(text) = storage::read(CAMPAIGN_FILE) {
+            for line in text.lines() {
+                let words = line.split_whitespace().collect::<Vec<&str>>();
+
+                if let ["level", number, stars] = words.as_slice() {
+                    if let (Ok(number), Ok(stars)) = (number.parse::<usize>(), stars.parse()) {
+                        if (1..=LEVELS.len()).contains(&number) {
+                            campaign.stars[number - 1] = stars;
+                        }
+                    }
+                }
+            }
+        }
+
+        campaign
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let mut text = String::new();
+        for (i, stars) in self.stars.iter().enumerate() {
+            writeln!(text, "level {} {}", i + 1, stars).unwrap();
+        }
+
+        storage::write(CAMPAIGN_FILE, &text)
+    }
+
+    /// Keeps the best stars of the level
+    pub fn record(&mut self, index: usize, stars: usize) {
+        if stars <= self.stars[index] {
+            return;
+        }
+
+        self.stars[index] = stars;
+
+        if let Err(error) = self.save() {
+            println!("Enregistrement de la campagne impossible : {error}");
+        }
+    }
+
+    pub const fn get_stars(&self, index: usize) -> usize {
+        self.stars[index]
+    }
+
+    pub const fn is_unlocked(&self, index: usize) -> bool {
+        index == 0 || self.stars[index - 1] > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(day: u32, earned: f32, headcount: usize) -> Progress {
+        Progress {
+            day,
+            earned,
+            headcount,
+            average_hope: Some(0.6),
+            lost: 0,
+        }
+    }
+
+    #[test]
+    fn early_win_settles_the_pending_objectives() {
+        // La démo : gagner 4000, perdre au plus 1 employé, garder l'espoir
+        let mut run = LevelRun::new(2);
+
+        run.record(&progress(3, 3000., 4));
+        assert!(!run.is_over());
+
+        run.record(&progress(4, 4200., 4));
+        assert!(run.is_won());
+        assert_eq!(run.get_statuses()[1], ObjectiveStatus::Pending);
+
+        assert_eq!(run.finish(), 3);
+        assert_eq!(run.get_statuses(), &[ObjectiveStatus::Done; 3]);
+    }
+
+    #[test]
+    fn objectives_to_reach_fail_when_the_level_is_won_early() {
+        // Recrutement : 6 employés, gagner 2500, garder l'espoir
+        let mut run = LevelRun::new(1);
+
+        run.record(&progress(2, 1000., 6));
+        assert!(run.is_won());
+
+        assert_eq!(run.finish(), 2);
+        assert_eq!(
+            run.get_statuses(),
+            &[
+                ObjectiveStatus::Done,
+                ObjectiveStatus::Failed,
+                ObjectiveStatus::Done
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_first_objective_gives_no_star() {
+        let mut run = LevelRun::new(2);
+
+        run.record(&Progress {
+            lost: 2,
+            ..progress(5, 3000., 2)
+        });
+        assert_eq!(run.get_statuses()[1], ObjectiveStatus::Failed);
+        assert!(!run.is_over());
+
+        run.record(&progress(7, 3500., 2));
+        assert!(run.is_over());
+        assert!(!run.is_won());
+
+        // L'espoir a été gardé, mais le niveau est perdu
+        assert_eq!(run.finish(), 0);
+        assert_eq!(
+            run.get_statuses(),
+            &[
+                ObjectiveStatus::Failed,
+                ObjectiveStatus::Failed,
+                ObjectiveStatus::Done
+            ]
+        );
+    }
+
+    #[test]
+    fn losses_before_the_level_do_not_count() {
+        // Premier jour : survivre, gagner 1000, ne perdre personne
+        let mut run = LevelRun::new(0);
+        run.lost_before = 1;
+
+        for day in 3..=4 {
+            run.record(&Progress {
+                lost: 1,
+                ..progress(day, 1200., 2)
+            });
+        }
+        assert_eq!(run.finish(), 3);
+
+        let mut run = LevelRun::new(0);
+        run.lost_before = 1;
+
+        run.record(&Progress {
+            lost: 2,
+            ..progress(2, 500., 1)
+        });
+        assert_eq!(run.get_statuses()[2], ObjectiveStatus::Failed);
+
+        run.record(&progress(3, 1200., 1));
+        run.record(&progress(4, 1200., 1));
+        assert_eq!(run.finish(), 2);
+    }
+}
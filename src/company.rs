@@ -0,0 +1,284 @@
+use crate::{
+    calendar,
+    employee::{DoorState, EmployeeState, Office},
+    history::{Fate, HistoryEvent},
+    layout::Layout,
+    qte::QteEffect,
+    rules::Rules,
+};
+
+/// Price of the second floor, each new floor costs this much more
+pub const FLOOR_PRICE: f32 = 3000.;
+pub const MAX_FLOORS: usize = 4;
+
+/// The floors of the company, each one an office with its own desks, door and
+/// window. They share a single wallet: every floor keeps a copy of the money,
+/// and what each one earned or spent is added to the wallet when syncing.
+pub struct Company {
+    floors: Vec<Office>,
+    current: usize,
+    money: f32,
+}
+
+impl Company {
+    pub fn new() -> Self {
+        let office = Office::new();
+
+        Self {
+            money: office.get_money(),
+            floors: vec![office],
+            current: 0,
+        }
+    }
+
+    /// Adds up what the floors earned and spent since the last sync
+    pub fn sync_money(&mut self) {
+        let delta = self
+            .floors
+            .iter()
+            .map(|f| f.get_money() - self.money)
+            .sum::<f32>();
+        self.money += delta;
+
+        for floor in &mut self.floors {
+            floor.set_money(self.money);
+        }
+    }
+
+    pub fn tick(&mut self) {
+        for floor in &mut self.floors {
+            floor.tick();
+        }
+
+        self.sync_money();
+    }
+
+    pub fn set_night(&mut self, night: bool) {
+        for floor in &mut self.floors {
+            floor.set_night(night);
+        }
+    }
+
+    pub fn next_day(&mut self) {
+        for floor in &mut self.floors {
+            floor.next_day();
+        }
+
+        if let Some(holiday) = calendar::get_holiday(self.get_day()) {
+            self.apply_qte_effect(&holiday.get_effect());
+            self.record_event(&HistoryEvent::Qte(holiday.get_name().to_owned()));
+        }
+
+        self.sync_money();
+    }
+
+    /// Starts over with a single floor and the money of the rules
+    pub fn set_rules(&mut self, rules: Rules) {
+        self.floors.truncate(1);
+        self.current = 0;
+        self.floors[0].set_rules(rules);
+        self.money = self.floors[0].get_money();
+    }
+
+    pub fn get_rules(&self) -> &Rules {
+        self.floors[0].get_rules()
+    }
+
+    /// The stats of every floor decay faster, or slower
+    pub fn set_decay_factor(&mut self, factor: f32) {
+        for floor in &mut self.floors {
+            floor.set_decay_factor(factor);
+        }
+    }
+
+    /// Replaces the furniture and starts over with a single floor
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.floors.truncate(1);
+        self.current = 0;
+        self.floors[0].set_layout(layout);
+        self.money = self.floors[0].get_money();
+    }
+
+    pub fn get_floor_price(&self) -> f32 {
+        FLOOR_PRICE * self.floors.len() as f32
+    }
+
+    pub fn can_buy_floor(&self) -> bool {
+        self.floors.len() < MAX_FLOORS && self.money >= self.get_floor_price()
+    }
+
+    /// Buys an empty floor with the saved layout, and goes there
+    pub fn buy_floor(&mut self) {
+        self.sync_money();
+
+        if !self.can_buy_floor() {
+            return;
+        }
+
+        self.money -= self.get_floor_price();
+
+        let mut floor = Office::new();
+        floor.set_rules(self.get_rules().clone());
+        floor.set_day(self.floors[0].get_day());
+        self.floors.push(floor);
+
+        for floor in &mut self.floors {
+            floor.set_money(self.money);
+        }
+
+        self.current = self.floors.len() - 1;
+    }
+
+    pub const fn switch_floor(&mut self, index: usize) {
+        if index < self.floors.len() {
+            self.current = index;
+        }
+    }
+
+    /// Moves the selected employee to the next floor with a free computer
+    pub fn transfer_selected_employee(&mut self) {
+        let count = self.floors.len();
+        let Some(target) = (1..count)
+            .map(|i| (self.current + i) % count)
+            .find(|&i| self.floors[i].has_free_computer())
+        else {
+            return;
+        };
+
+        if let Some(employee) = self.floors[self.current].take_selected_employee() {
+            self.floors[target].receive_employee(employee);
+        }
+    }
+
+    pub fn get_current(&self) -> &Office {
+        &self.floors[self.current]
+    }
+
+    pub fn get_current_mut(&mut self) -> &mut Office {
+        &mut self.floors[self.current]
+    }
+
+    pub const fn get_current_index(&self) -> usize {
+        self.current
+    }
+
+    pub const fn floors_count(&self) -> usize {
+        self.floors.len()
+    }
+
+    pub const fn get_money(&self) -> f32 {
+        self.money
+    }
+
+    pub fn employees_count(&self) -> usize {
+        self.floors.iter().map(Office::employees_count).sum()
+    }
+
+    pub fn jumped_count(&self) -> usize {
+        self.floors.iter().map(Office::jumped_count).sum()
+    }
+
+    pub fn fate_count(&self, fate: Fate) -> usize {
+        self.floors.iter().map(|f| f.fate_count(fate)).sum()
+    }
+
+    /// Whether the door of a floor is closed, a broken one does not count
+    pub fn is_any_door_closed(&self) -> bool {
+        self.floors
+            .iter()
+            .any(|f| matches!(f.get_door_state(), DoorState::Closed))
+    }
+
+    /// Employees who died, fled, jumped or burned
+    pub fn lost_count(&self) -> usize {
+        [Fate::Died, Fate::Fled, Fate::Jumped, Fate::Burned]
+            .into_iter()
+            .map(|fate| self.fate_count(fate))
+            .sum()
+    }
+
+    /// Average hope of the employees still working, on every floor
+    pub fn average_hope(&self) -> Option<f32> {
+        let hopes = self
+            .floors
+            .iter()
+            .flat_map(Office::iter_employees)
+            .filter(|e| matches!(e.get_state(), EmployeeState::Alive | EmployeeState::Home))
+            .map(|e| e.get_hope())
+            .collect::<Vec<f32>>();
+
+        if hopes.is_empty() {
+            None
+        } else {
+            Some(hopes.iter().sum::<f32>() / hopes.len() as f32)
+        }
+    }
+
+    pub fn hired_count(&self) -> usize {
+        self.floors.iter().map(Office::hired_count).sum()
+    }
+
+    /// Money generated by the employees of every floor since the start
+    pub fn get_earned(&self) -> f32 {
+        self.floors.iter().map(Office::get_earned).sum()
+    }
+
+    pub fn get_day(&self) -> u32 {
+        self.floors[0].get_day()
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.floors.iter().all(Office::is_game_over)
+    }
+
+    pub fn take_fire_alarm(&mut self) -> bool {
+        self.floors.iter_mut().any(Office::take_fire_alarm)
+    }
+
+    /// Whether a door broke since the last call, on any floor
+    pub fn take_door_broken(&mut self) -> bool {
+        let mut broken = false;
+        for floor in &mut self.floors {
+            broken |= floor.take_door_broken();
+        }
+        broken
+    }
+
+    pub fn is_on_fire(&self) -> bool {
+        self.floors.iter().any(Office::is_on_fire)
+    }
+
+    pub fn extinguish_all_fires(&mut self) {
+        for floor in &mut self.floors {
+            floor.extinguish_all_fires();
+        }
+    }
+
+    pub fn record_event(&self, event: &HistoryEvent) {
+        for floor in &self.floors {
+            floor.record_event(event);
+        }
+    }
+
+    /// Every employee feels the effect, but the money is only counted once
+    /// and the hires and deaths happen on the current floor
+    pub fn apply_qte_effect(&mut self, effect: &QteEffect) {
+        let shared = QteEffect {
+            money_delta: 0.,
+            employee_delta: 0,
+            ..effect.clone()
+        };
+
+        for (i, floor) in self.floors.iter_mut().enumerate() {
+            floor.apply_qte_effect(if i == self.current { effect } else { &shared });
+        }
+
+        self.sync_money();
+    }
+
+    pub fn bonus_meth(&mut self) {
+        for floor in &mut self.floors {
+            floor.bonus_meth();
+        }
+    }
+}
@@ -1,16 +1,46 @@
-use std::f32::consts::PI;
+use std::{cell::RefCell, f32::consts::PI, rc::Rc};
 
 use macroquad::prelude::*;
 
 use crate::{
+    achievements::{Achievement, Achievements},
     assets,
+    calendar::{self, Season},
+    campaign::{Campaign, Level, LevelRun, ObjectiveStatus, Tool, LEVELS},
+    company::{Company, MAX_FLOORS},
+    editor::LayoutEditor,
     employee::{
-        DoorState, EmployeeAction, EmployeeState, EMPLOYEE_RADIUS, EMPLOYEE_RUNNING_SPEED,
-        EMPLOYEE_SPEED, MIDDLE_LANE,
+        Computer, DoorState, Employee, EmployeeAction, EmployeeState, Office, SelectionFilter,
+        Stat, WindowState, COMPUTER_RADIUS, DOOR_REPAIR_COST, EMPLOYEE_RADIUS,
+        EMPLOYEE_RUNNING_SPEED, EMPLOYEE_SPEED, MIDDLE_LANE, REPAIR_COST, WINDOW_BARRICADE_COST,
+        WINDOW_LOCK_COST,
     },
-    Game, DOOR_CD, METH_CD, RH_CD,
+    fire::EXTINGUISH_COST,
+    game_mode::{GameMode, ENDLESS_MILESTONE_DAYS},
+    highscores::HighScores,
+    history::{HistoryEntry, MemorialEntry},
+    identity::Identity,
+    layout::{Layout, GRID_SIZE, ROOM},
+    policy::{describe_rule, PolicyTarget, Trigger, TEMPLATES},
+    relationship::RelationshipKind,
+    run_stats::RunSummary,
+    screen_layout::{to_panel, ScreenLayout},
+    Game, DOOR_CD, FPS,
 };
 
+/// Buttons of a rule in the policy editor
+#[derive(Clone, Copy)]
+pub enum PolicyButton {
+    Lower,
+    Raise,
+    Toggle,
+    Remove,
+}
+
+impl PolicyButton {
+    pub const ALL: [Self; 4] = [Self::Lower, Self::Raise, Self::Toggle, Self::Remove];
+}
+
 #[derive(Debug)]
 struct RandomPassing {
     pub x: f32,
@@ -24,6 +54,10 @@ const MAX_PERIOD_WITHOUT_PASSING: f32 = 3.;
 
 const _TRANSPARENT: Color = Color::new(255., 255., 255., 0.);
 const LIGHTGRAY_ALPHA: Color = Color::new(0.78, 0.78, 0.78, 0.4);
+/// How long a new milestone of the endless mode stands out, in seconds
+const MILESTONE_HIGHLIGHT_TIME: f64 = 4.;
+/// Over the buttons of the tools a campaign level takes away
+const LOCKED_TOOL_COLOR: Color = Color::new(0.2, 0.2, 0.2, 0.7);
 
 pub const OFFICE_WIDTH: u32 = 1280;
 pub const OFFICE_HEIGHT: u32 = 720;
@@ -44,26 +78,78 @@ pub const ANIMATION_SPEED: f32 = 0.1;
 const DOOR_SPEED: f32 = 0.3;
 const WINDOW_SPEED: f32 = 0.05;
 
+const MAX_OFFICE_ZOOM: f32 = 3.;
+/// Zoom factor of one mouse wheel notch
+const ZOOM_STEP: f32 = 1.15;
+/// How fast the camera catches up with the followed employee
+const FOLLOW_SPEED: f32 = 0.1;
+
+/// Outside of the building, seen through the wall on the right of the office
+const SKY: [Vec2; 3] = [
+    Vec2::new(1100., 0.),
+    Vec2::new(1280., 0.),
+    Vec2::new(1280., 650.),
+];
+/// Snowflakes, leaves or petals falling outside
+const WEATHER_PARTICLES: u32 = 40;
+/// Decorated in december, in the corner of the corridor
+const CHRISTMAS_TREE: Vec2 = Vec2::new(130., 40.);
+
 const FONT_SIZE_INFO: f32 = 150.;
 const FONT_SIZE_GLOBAL: f32 = 50.;
+const FONT_SIZE_DAY: f32 = 30.;
 const FONT_SIZE_PERSONNAL: f32 = 100.;
 const FONT_SIZE_BAR: u16 = 75;
 
 const PERSONNAL_LINES_THICKNES: f32 = 35.;
 
-const DESCRIPTION_BUTTON_HOPE: &str = "Laissez votre employée faire un appel vidéo avec sa famille. Mais attention ! Les relations sociales ne participe pas à l'avancement du projet.";
+const TOOLTIP_FONT_SIZE: u16 = 18;
+const TOOLTIP_WIDTH: f32 = 300.;
+const TOOLTIP_PADDING: f32 = 10.;
+/// Distance between the mouse and the corner of the tooltip
+const TOOLTIP_OFFSET: f32 = 18.;
+
+const DESCRIPTION_BUTTON_HOPE: &str = "Envoyez votre employée faire un appel vidéo avec sa famille au téléphone. Mais attention ! Les relations sociales ne participe pas à l'avancement du projet.";
 const DESCRIPTION_BUTTON_ENERGY: &str =
-    "Laissez votre employée dormir. Mais attention ! Dormir est une perte de temps.";
-const DESCRIPTION_BUTTON_SATISFACTION: &str = "Laissez votre employée faire une pause. Mais attention ! Les pauses ne sont absolument pas nécessaire à l'avancement du projet.";
-const DESCRIPTION_BUTTON_SATIETY: &str = "Laissez votre employée manger. Mais attention ! Seule la nourriture spirituelle qu'est le travail devrait leur suffire.";
+    "Envoyez votre employée dormir au dortoir. Mais attention ! Dormir est une perte de temps.";
+const DESCRIPTION_BUTTON_SATISFACTION: &str = "Envoyez votre employée faire une pause en salle de pause. Mais attention ! Les pauses ne sont absolument pas nécessaire à l'avancement du projet.";
+const DESCRIPTION_BUTTON_SATIETY: &str = "Envoyez votre employée manger à la cuisine. Mais attention ! Seule la nourriture spirituelle qu'est le travail devrait leur suffire.";
+
+const DESCRIPTION_BUTTON_REPAIR: &str =
+    "Appelez un technicien pour réparer l'ordinateur. Pas cher, mais il prend son temps.";
+const DESCRIPTION_BUTTON_REPLACE: &str =
+    "Achetez un ordinateur neuf du même modèle, disponible tout de suite.";
+const DESCRIPTION_BUTTON_UPGRADE: &str =
+    "Achetez un meilleur ordinateur. Votre employé produira plus, qu'il le veuille ou non.";
+
+const DESCRIPTION_BUTTON_EXTINGUISH: &str =
+    "Videz un extincteur sur le feu avant qu'il ne se propage aux bureaux voisins.";
 
 const DESCRIPTION_BUTTON_CLEAN: &str = "Libérez de l'espace en vous débarassant de votre employé";
 
 const DESCRIPTION_BUTTON_DOOR: &str =
     "Ouvrez la porte à vos employée. Plus la porte est ouverte plus ils auront d'espoir.";
+const DESCRIPTION_BUTTON_DOOR_BROKEN: &str = "Vos employés ont enfoncé la porte ! Elle ne peut plus être fermée tant que vous ne l'avez pas faite réparer.";
+const DESCRIPTION_BUTTON_WINDOW_UNLOCKED: &str = "La fenêtre s'ouvre pour qui veut sauter. Posez un verrou, que les plus désespérés pourront tout de même forcer. Le verrou coûte un peu chaque jour.";
+const DESCRIPTION_BUTTON_WINDOW_LOCKED: &str = "La fenêtre est verrouillée. Barricadez-la pour que plus personne ne passe, mais vos employés se sentiront enfermés. L'entretien coûte chaque jour.";
+const DESCRIPTION_BUTTON_WINDOW_BARRICADED: &str =
+    "La fenêtre est barricadée. Retirez les planches pour la libérer.";
 const DESCRIPTION_BUTTON_METH: &str =
-    "Donnez un coup de boost à vos employée en leur offrant un breuvage (arrangé par vos soin). Coût : 1000";
-const DESCRIPTION_BUTTON_RH: &str = "Le pôle RH se démenera afin de vous trouvez LE candidat pour remplir vos rang (probablement un stagiaire). Coût : 200";
+    "Donnez un coup de boost à vos employée en leur offrant un breuvage (arrangé par vos soin). Coût :";
+const DESCRIPTION_BUTTON_RH: &str = "Le pôle RH se démenera afin de vous trouvez LE candidat pour remplir vos rang (probablement un stagiaire). Coût :";
+
+const DESCRIPTION_BUTTON_MEMORIAL: &str =
+    "Consultez le mémorial de tous les employés qui ont quitté l'entreprise, d'une manière ou d'une autre.";
+const DESCRIPTION_BUTTON_HISTORY: &str =
+    "Consultez l'histoire de votre employé depuis son embauche.";
+const DESCRIPTION_BUTTON_TRANSFER: &str =
+    "Mutez votre employé à l'étage suivant qui a un ordinateur libre. Il devra repasser par la porte.";
+const DESCRIPTION_BUTTON_FOLLOW: &str = "La caméra suit l'employé sélectionné. Zoomez avec la molette et déplacez la vue avec le clic droit.";
+const DESCRIPTION_BUTTON_FILTER: &str = "Shift+clic ajoute un employé à la sélection et glisser sélectionne toute une zone. Sélectionnez d'un coup tous les employés";
+const DESCRIPTION_BUTTON_POLICIES: &str = "Automatisez les actions de vos employés avec des règles. Chaque règle se paie tous les jours, et un employé retourne travailler un moment avant que la suivante ne s'applique.";
+const DESCRIPTION_LOCKED_TOOL: &str =
+    "Cet outil n'est pas encore disponible à ce niveau de la campagne.";
+const DESCRIPTION_BUTTON_FLOOR: &str = "Achetez un nouvel étage, avec ses bureaux, sa porte et sa fenêtre. Les embauches se font à l'étage affiché.";
 
 const DESCRIPTION_HOPE: &str = "L'espoir de vos employé reflète leurs pensé quand à leur avenir chez vous (pouvoir partir). Trop d'espoir pourrait conduire à une tentive de fuite, alors que pas assez pourrait être facheux. La porte ouverte augmente l'espoir et inversement.";
 const DESCRIPTION_SATISFACTION: &str =
@@ -104,20 +190,26 @@ pub struct Drawing {
     button_personnal_energy: Rect,
     button_personnal_satisfaction: Rect,
 
+    button_personnal_history: Rect,
+    button_personnal_transfer: Rect,
+    button_computer_repair: Rect,
+    button_computer_replace: Rect,
+    button_computer_upgrade: Rect,
+    button_computer_extinguish: Rect,
+
     // Button global
     button_global_door: Rect,
     button_global_meth: Rect,
     button_global_rh: Rect,
+    button_global_memorial: Rect,
+    button_global_window: Rect,
+    button_global_policies: Rect,
 
     // Button qte
     button_choice_1: Rect,
     button_choice_2: Rect,
 
     // Rect render
-    rect_office: Rect,
-    rect_info: Rect,
-    rect_personnal_stat: Rect,
-    rect_global_stat: Rect,
 
     // Bar stat
     bar_satisfaction: Rect,
@@ -128,6 +220,19 @@ pub struct Drawing {
     random_passing: Option<RandomPassing>,
     start_wainting_passing: f32,
     wainting_passing_time: f32,
+
+    show_history: bool,
+    show_memorial: bool,
+    show_policies: bool,
+
+    // Office camera
+    office_zoom: f32,
+    office_center: Vec2,
+    follow_selected: bool,
+    /// Last mouse position in the office view while dragging it
+    pan_anchor: Option<Vec2>,
+    /// Where the box selection started, in the office
+    selection_anchor: Option<Vec2>,
 }
 
 impl Drawing {
@@ -215,11 +320,22 @@ impl Drawing {
             button_personnal_energy: Rect::new(1325., 335., 250., 150.),
             button_personnal_satiety: Rect::new(1325., 535., 250., 150.),
             button_personnal_hope: Rect::new(1325., 735., 250., 150.),
+            button_personnal_history: Rect::new(1130., 10., 220., 90.),
+            button_personnal_transfer: Rect::new(1370., 10., 220., 90.),
+
+            // Button computer
+            button_computer_repair: Rect::new(100., 550., 400., 150.),
+            button_computer_replace: Rect::new(600., 550., 400., 150.),
+            button_computer_upgrade: Rect::new(1100., 550., 400., 150.),
+            button_computer_extinguish: Rect::new(100., 730., 400., 150.),
 
             // Button global
             button_global_door: Rect::new(120., 100., 125., 125.),
             button_global_meth: Rect::new(270., 100., 125., 125.),
             button_global_rh: Rect::new(420., 100., 125., 125.),
+            button_global_memorial: Rect::new(570., 100., 125., 125.),
+            button_global_window: Rect::new(720., 120., 250., 105.),
+            button_global_policies: Rect::new(985., 120., 125., 105.),
 
             // Button qte
             button_choice_1: Rect::new(300., 1950., 450., 200.),
@@ -231,52 +347,123 @@ impl Drawing {
             bar_satiety: Rect::new(300., 560., 1000., 100.),
             bar_hope: Rect::new(300., 760., 1000., 100.),
 
-            //Render rect
-            rect_office: Rect::new(
-                GAME_WINDOW_WIDTH as f32 * 0.3,
-                GAME_WINDOW_HEIGHT as f32 * 0.3,
-                GAME_WINDOW_WIDTH as f32 * 0.69,
-                GAME_WINDOW_HEIGHT as f32 * 0.69,
-            ),
-            rect_info: Rect::new(
-                GAME_WINDOW_WIDTH as f32 * 0.01,
-                GAME_WINDOW_HEIGHT as f32 * 0.3,
-                GAME_WINDOW_WIDTH as f32 * 0.28,
-                GAME_WINDOW_HEIGHT as f32 * 0.69,
-            ),
-            rect_personnal_stat: Rect::new(
-                GAME_WINDOW_WIDTH as f32 * 0.01,
-                GAME_WINDOW_HEIGHT as f32 * 0.01,
-                GAME_WINDOW_WIDTH as f32 * 0.28,
-                GAME_WINDOW_HEIGHT as f32 * 0.28,
-            ),
-            rect_global_stat: Rect::new(
-                GAME_WINDOW_WIDTH as f32 * 0.3,
-                GAME_WINDOW_HEIGHT as f32 * 0.01,
-                GAME_WINDOW_WIDTH as f32 * 0.69,
-                GAME_WINDOW_HEIGHT as f32 * 0.28,
-            ),
-
             random_passing: None,
             start_wainting_passing: get_time() as f32,
             wainting_passing_time: MIN_PERIOD_WITHOUT_PASSING,
+
+            show_history: false,
+            show_memorial: false,
+            show_policies: false,
+
+            office_zoom: 1.,
+            office_center: vec2(OFFICE_WIDTH as f32 / 2., OFFICE_HEIGHT as f32 / 2.),
+            follow_selected: false,
+            pan_anchor: None,
+            selection_anchor: None,
+        }
+    }
+
+    /// Part of the office seen through the office view
+    fn office_view_rect(&self) -> Rect {
+        let size = vec2(OFFICE_WIDTH as f32, OFFICE_HEIGHT as f32) / self.office_zoom;
+
+        Rect::new(
+            self.office_center.x - size.x / 2.,
+            self.office_center.y - size.y / 2.,
+            size.x,
+            size.y,
+        )
+    }
+
+    /// Keeps the view inside the office
+    fn clamp_office_center(&mut self) {
+        let half = vec2(OFFICE_WIDTH as f32, OFFICE_HEIGHT as f32) / self.office_zoom / 2.;
+
+        self.office_center = self
+            .office_center
+            .clamp(half, vec2(OFFICE_WIDTH as f32, OFFICE_HEIGHT as f32) - half);
+    }
+
+    /// Zooms in or out around the point of the office view under the mouse
+    pub fn zoom_office(&mut self, wheel: f32, view_pos: Vec2) {
+        let world_pos = self.convert_office_view_world(view_pos);
+
+        self.office_zoom =
+            (self.office_zoom * ZOOM_STEP.powf(wheel.signum())).clamp(1., MAX_OFFICE_ZOOM);
+
+        let offset = view_pos - vec2(OFFICE_WIDTH as f32, OFFICE_HEIGHT as f32) / 2.;
+        self.office_center = world_pos - offset / self.office_zoom;
+        self.clamp_office_center();
+    }
+
+    pub const fn start_office_pan(&mut self, view_pos: Vec2) {
+        self.pan_anchor = Some(view_pos);
+    }
+
+    /// Drags the office with the mouse, which stops following the employee
+    pub fn pan_office(&mut self, view_pos: Vec2) {
+        if let Some(anchor) = self.pan_anchor {
+            if anchor != view_pos {
+                self.follow_selected = false;
+            }
+
+            self.office_center += (anchor - view_pos) / self.office_zoom;
+            self.clamp_office_center();
+            self.pan_anchor = Some(view_pos);
         }
     }
 
-    pub fn get_rect_office(&self) -> &Rect {
-        &self.rect_office
+    pub const fn stop_office_pan(&mut self) {
+        self.pan_anchor = None;
+    }
+
+    pub const fn start_selection(&mut self, world_pos: Vec2) {
+        self.selection_anchor = Some(world_pos);
+    }
+
+    fn get_selection_rect(&self, world_pos: Vec2) -> Option<Rect> {
+        let anchor = self.selection_anchor?;
+        let min = anchor.min(world_pos);
+        let size = anchor.max(world_pos) - min;
+
+        Some(Rect::new(min.x, min.y, size.x, size.y))
+    }
+
+    /// Ends the box selection, and returns the selected box if there was one
+    pub fn stop_selection(&mut self, world_pos: Vec2) -> Option<Rect> {
+        let rect = self.get_selection_rect(world_pos);
+        self.selection_anchor = None;
+        rect
+    }
+
+    /// Selects every employee matching a `SelectionFilter::ALL` filter
+    pub const fn filter_button_rect(index: usize) -> Rect {
+        Rect::new(1110., (index as f32).mul_add(60., 75.), 150., 50.)
+    }
+
+    pub const fn toggle_follow_selected(&mut self) {
+        self.follow_selected = !self.follow_selected;
+    }
+
+    /// Toggles the camera following the selected employee
+    pub const fn follow_button_rect() -> Rect {
+        Rect::new(1110., 15., 150., 50.)
+    }
+
+    pub fn get_rect_office(&self) -> Rect {
+        ScreenLayout::current().office
     }
 
-    pub fn get_rect_global_stat(&self) -> &Rect {
-        &self.rect_global_stat
+    pub fn get_rect_global_stat(&self) -> Rect {
+        ScreenLayout::current().global_stat
     }
 
-    pub fn get_rect_personnal_stat(&self) -> &Rect {
-        &self.rect_personnal_stat
+    pub fn get_rect_personnal_stat(&self) -> Rect {
+        ScreenLayout::current().personnal_stat
     }
 
-    pub fn get_rect_info(&self) -> &Rect {
-        &self.rect_info
+    pub fn get_rect_info(&self) -> Rect {
+        ScreenLayout::current().info
     }
 
     pub fn get_button_hope(&self) -> &Rect {
@@ -307,6 +494,342 @@ impl Drawing {
         &self.button_global_door
     }
 
+    pub const fn get_button_history(&self) -> &Rect {
+        &self.button_personnal_history
+    }
+
+    pub const fn get_button_transfer(&self) -> &Rect {
+        &self.button_personnal_transfer
+    }
+
+    pub const fn get_button_memorial(&self) -> &Rect {
+        &self.button_global_memorial
+    }
+
+    pub const fn get_button_policies(&self) -> &Rect {
+        &self.button_global_policies
+    }
+
+    pub const fn get_button_window(&self) -> &Rect {
+        &self.button_global_window
+    }
+
+    pub const fn get_button_computer_repair(&self) -> &Rect {
+        &self.button_computer_repair
+    }
+
+    pub const fn get_button_computer_replace(&self) -> &Rect {
+        &self.button_computer_replace
+    }
+
+    pub const fn get_button_computer_upgrade(&self) -> &Rect {
+        &self.button_computer_upgrade
+    }
+
+    pub const fn get_button_computer_extinguish(&self) -> &Rect {
+        &self.button_computer_extinguish
+    }
+
+    pub const fn is_history_shown(&self) -> bool {
+        self.show_history
+    }
+
+    pub const fn toggle_history(&mut self) {
+        self.show_history = !self.show_history;
+    }
+
+    pub const fn toggle_memorial(&mut self) {
+        self.show_memorial = !self.show_memorial;
+        self.show_policies = false;
+    }
+
+    pub const fn is_policies_shown(&self) -> bool {
+        self.show_policies
+    }
+
+    pub const fn toggle_policies(&mut self) {
+        self.show_policies = !self.show_policies;
+        self.show_memorial = false;
+    }
+
+    /// Button of the rule at `index` in the policy editor
+    pub fn policy_button_rect(index: usize, button: PolicyButton) -> Rect {
+        let y = Self::policy_row_y(index) - 65.;
+
+        match button {
+            PolicyButton::Lower => Rect::new(1230., y, 85., 85.),
+            PolicyButton::Raise => Rect::new(1325., y, 85., 85.),
+            PolicyButton::Toggle => Rect::new(1420., y, 140., 85.),
+            PolicyButton::Remove => Rect::new(1570., y, 85., 85.),
+        }
+    }
+
+    /// Adds the template at `index` of `TEMPLATES`
+    pub const fn policy_template_rect(index: usize) -> Rect {
+        Rect::new(200., (index as f32).mul_add(115., 1480.), 1490., 95.)
+    }
+
+    const fn policy_row_y(index: usize) -> f32 {
+        (index as f32).mul_add(135., 520.)
+    }
+
+    /// Red when the employee is doing the action, orange when they are on
+    /// their way or waiting for a free slot
+    fn action_button_color(employee: &Employee, action: EmployeeAction) -> Color {
+        if employee.action != action {
+            GREEN
+        } else if employee.is_using_zone() {
+            RED
+        } else {
+            ORANGE
+        }
+    }
+
+    fn draw_window_state(state: WindowState) {
+        match state {
+            WindowState::Unlocked => (),
+            WindowState::Locked => {
+                draw_rectangle(1060., 350., 30., 20., DARKGRAY);
+                draw_circle_lines(1075., 350., 8., 3., DARKGRAY);
+            }
+            WindowState::Barricaded => {
+                for y in [300., 345., 390.] {
+                    draw_rectangle_ex(
+                        1050.,
+                        y,
+                        60.,
+                        18.,
+                        DrawRectangleParams {
+                            rotation: 0.15,
+                            color: Color::new(0.55, 0.35, 0.15, 1.),
+                            ..Default::default()
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_technicians(office: &Office) {
+        for technician in office.iter_technicians() {
+            draw_texture_ex(
+                &assets::MANAGER_TEXTURE,
+                technician.get_pos().x - EMPLOYEE_RADIUS,
+                technician.get_pos().y - EMPLOYEE_RADIUS,
+                WHITE,
+                DrawTextureParams {
+                    rotation: technician.get_rotation(),
+                    dest_size: Some(Vec2::new(100.0, 100.0)),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    fn draw_computer_panel(&self, computer: &Computer, burning: bool, money: f32) {
+        let title = format!("Ordinateur {}", computer.tier.get_name());
+        draw_text_ex(
+            &title,
+            0.,
+            FONT_SIZE_PERSONNAL,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: FONT_SIZE_PERSONNAL as u16,
+                color: BLACK,
+                ..Default::default()
+            },
+        );
+
+        let status = if burning {
+            "En feu !".to_owned()
+        } else if computer.being_repaired {
+            format!(
+                "En réparation : {}%",
+                (computer.repair_progress * 100.).round()
+            )
+        } else if computer.broken {
+            "En panne".to_owned()
+        } else {
+            "En état de marche".to_owned()
+        };
+
+        for (i, line) in [
+            format!("Productivité : x{}", computer.tier.get_productivity()),
+            status,
+        ]
+        .iter()
+        .enumerate()
+        {
+            draw_text_ex(
+                line,
+                10.,
+                (i as f32).mul_add(100., FONT_SIZE_PERSONNAL + 150.),
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: FONT_SIZE_BAR,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let available = |condition: bool| if condition { GREEN } else { LIGHTGRAY };
+
+        let can_fix = computer.broken && !computer.being_repaired && !burning;
+
+        Self::draw_text_button(
+            self.button_computer_repair,
+            available(can_fix && money >= REPAIR_COST),
+            &format!("Réparer ({REPAIR_COST})"),
+            50,
+        );
+        Self::draw_text_button(
+            self.button_computer_replace,
+            available(can_fix && money >= computer.tier.get_price()),
+            &format!("Remplacer ({})", computer.tier.get_price()),
+            50,
+        );
+        if let Some(next) = computer.tier.next() {
+            Self::draw_text_button(
+                self.button_computer_upgrade,
+                available(!computer.broken && money >= next.get_price()),
+                &format!("Améliorer ({})", next.get_price()),
+                50,
+            );
+        }
+        if burning {
+            Self::draw_text_button(
+                self.button_computer_extinguish,
+                available(money >= EXTINGUISH_COST),
+                &format!("Éteindre ({EXTINGUISH_COST})"),
+                50,
+            );
+        }
+    }
+
+    /// Draws the office without furniture, and the desks of the layout
+    pub fn draw_layout(layout: &Layout) {
+        draw_texture_ex(
+            &assets::OFFICE_FLOOR_TEXTURE,
+            0.,
+            0.,
+            WHITE,
+            DrawTextureParams {
+                source: None,
+                rotation: 0.0,
+                dest_size: Some(Vec2::new(1280.0, 720.0)),
+                ..Default::default()
+            },
+        );
+
+        for desk in &layout.desks {
+            draw_texture_ex(
+                &assets::DESK_TEXTURE,
+                desk.pos.x,
+                desk.pos.y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(Vec2::new(135., 158.)),
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    /// The sky and the weather outside, and the decorations of the month
+    fn draw_season(day: u32) {
+        let season = calendar::get_season(day);
+        let (sky, particle, speed) = match season {
+            Season::Spring => (Some(Color::new(0.55, 0.9, 0.8, 1.)), Some(PINK), 25.),
+            Season::Summer => (None, None, 0.),
+            Season::Autumn => (Some(Color::new(0.65, 0.75, 0.8, 1.)), Some(ORANGE), 35.),
+            Season::Winter => (Some(Color::new(0.8, 0.85, 0.9, 1.)), Some(WHITE), 45.),
+        };
+
+        if let Some(sky) = sky {
+            draw_triangle(SKY[0], SKY[1], SKY[2], sky);
+        }
+
+        if season == Season::Summer {
+            draw_circle(SKY[1].x - 35., SKY[1].y + 45., 25., YELLOW);
+        }
+
+        if let Some(color) = particle {
+            let time = get_time() as f32;
+
+            for i in 0..WEATHER_PARTICLES {
+                // Spread the particles with a cheap hash of their index
+                let x = (time + i as f32)
+                    .sin()
+                    .mul_add(6., SKY[0].x + (i * 37 % 180) as f32);
+                let y = time.mul_add(speed, (i * 97 % 720) as f32) % SKY[2].y;
+
+                // Only on the outside of the wall
+                if x > SKY[0].x + y * (SKY[2].x - SKY[0].x) / SKY[2].y {
+                    draw_circle(x, y, 3., color);
+                }
+            }
+        }
+
+        if calendar::get_month(day) == 11 {
+            let (x, y) = (CHRISTMAS_TREE.x, CHRISTMAS_TREE.y);
+
+            draw_rectangle(x - 6., y + 80., 12., 15., BROWN);
+            for i in 0..3 {
+                let top = (i as f32).mul_add(22., y);
+                let half = (i as f32).mul_add(8., 18.);
+                draw_triangle(
+                    vec2(x, top),
+                    vec2(x - half, top + 40.),
+                    vec2(x + half, top + 40.),
+                    DARKGREEN,
+                );
+            }
+            draw_circle(x, y, 6., GOLD);
+        }
+    }
+
+    fn draw_zones(office: &Office) {
+        for zone in office.iter_zones() {
+            let rect = zone.get_rect();
+            let kind = zone.get_kind();
+
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, kind.get_color());
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 3., DARKGRAY);
+
+            draw_text_ex(
+                format!(
+                    "{} {}/{}",
+                    kind.get_name(),
+                    zone.occupied_count(),
+                    zone.capacity()
+                ),
+                rect.x + 5.,
+                rect.y + 18.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 16,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+
+            if zone.queue_len() > 0 {
+                draw_text_ex(
+                    format!("+{} en attente", zone.queue_len()),
+                    rect.x + 5.,
+                    rect.y + 36.,
+                    TextParams {
+                        font: Some(&assets::FONT),
+                        font_size: 14,
+                        color: DARKGRAY,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+
     pub fn get_button_choice_1(&self) -> &Rect {
         &self.button_choice_1
     }
@@ -359,22 +882,53 @@ impl Drawing {
         }
     }
 
-    pub fn draw_office_full(&mut self, game: &Game) {
-        set_camera(&self.main_camera);
+    /// Draws an employee by stacking the tinted layers of the employee sprite
+    fn draw_employee_sprite(identity: &Identity, x: f32, y: f32, rotation: f32, size: Vec2) {
+        let params = DrawTextureParams {
+            rotation,
+            dest_size: Some(size),
+            ..Default::default()
+        };
 
-        clear_background(WHITE);
         draw_texture_ex(
-            &assets::OFFICE_TEXTURE,
-            0.,
-            0.,
-            WHITE,
-            DrawTextureParams {
-                source: None,
-                rotation: 0.0,
-                dest_size: Some(Vec2::new(1280.0, 720.0)),
-                ..Default::default()
+            &assets::EMPLOYEE_SHIRT_LAYER,
+            x,
+            y,
+            identity.shirt_color,
+            params.clone(),
+        );
+        draw_texture_ex(
+            &assets::EMPLOYEE_SKIN_LAYER,
+            x,
+            y,
+            identity.skin_tone,
+            params.clone(),
+        );
+        draw_texture_ex(
+            &assets::EMPLOYEE_HAIR_LAYER,
+            x,
+            y,
+            if identity.bald {
+                identity.skin_tone
+            } else {
+                identity.hair_color
             },
+            params.clone(),
         );
+        draw_texture_ex(&assets::EMPLOYEE_EYES_LAYER, x, y, WHITE, params);
+    }
+
+    /// Position of the trait badges in the personnal stat panel
+    const fn trait_badge_rect(index: usize) -> Rect {
+        Rect::new((index as f32).mul_add(330., 650.), 127., 320., 32.)
+    }
+
+    pub fn draw_office_full(&mut self, game: &Game) {
+        set_camera(&self.main_camera);
+
+        clear_background(WHITE);
+        Self::draw_layout(game.get_office().get_layout());
+        Self::draw_season(game.get_office().get_day());
 
         if let DoorState::Closed = game.get_office().get_door_state() {
             if self.door_rotation < 0.0 {
@@ -388,10 +942,17 @@ impl Drawing {
             } else {
                 self.door_rotation = -PI / 2.
             }
+        } else if matches!(game.get_office().get_door_state(), DoorState::Broken) {
+            // Hanging from a single hinge
+            self.door_rotation = -PI / 3.;
         }
 
         draw_texture_ex(
-            &assets::DOOR_TEXTURE,
+            if matches!(game.get_office().get_door_state(), DoorState::Broken) {
+                &assets::DOOR_BROKEN_TEXTURE
+            } else {
+                &assets::DOOR_TEXTURE
+            },
             340.,
             320.,
             WHITE,
@@ -438,6 +999,7 @@ impl Drawing {
                 ..Default::default()
             },
         );
+        Self::draw_window_state(game.get_office().get_window_state());
 
         // Draw the computers
         for c in game.get_office().iter_computers_mut() {
@@ -452,35 +1014,68 @@ impl Drawing {
                 texture,
                 computer.position.x - 25.0,
                 computer.position.y - 25.0,
-                WHITE,
+                computer.tier.get_color(),
                 DrawTextureParams {
                     rotation: computer.rotation,
                     dest_size: Some(Vec2::new(50.0, 50.0)),
                     ..Default::default()
                 },
             );
+
+            if computer.being_repaired {
+                draw_rectangle(
+                    computer.position.x - 25.,
+                    computer.position.y - 40.,
+                    50.,
+                    8.,
+                    DARKGRAY,
+                );
+                draw_rectangle(
+                    computer.position.x - 25.,
+                    computer.position.y - 40.,
+                    50. * computer.repair_progress,
+                    8.,
+                    GREEN,
+                );
+            }
+
+            if game
+                .get_office()
+                .get_selected_computer()
+                .is_some_and(|selected| Rc::ptr_eq(selected, &c))
+            {
+                draw_circle_lines(
+                    computer.position.x,
+                    computer.position.y,
+                    COMPUTER_RADIUS,
+                    4.,
+                    YELLOW,
+                );
+            }
         }
+        Self::draw_zones(game.get_office());
+        Self::draw_technicians(game.get_office());
         self.handle_passer();
 
         // Draw employees
         for mut e in game.get_office().iter_employees_mut() {
-            draw_texture_ex(
-                &assets::EMPLOYEE_TEXTURE,
+            if matches!(e.get_state(), EmployeeState::Home) {
+                continue;
+            }
+
+            Self::draw_employee_sprite(
+                e.get_identity(),
                 e.get_pos().x - EMPLOYEE_RADIUS,
                 e.get_pos().y - EMPLOYEE_RADIUS,
-                WHITE,
-                DrawTextureParams {
-                    rotation: e.get_rotation(),
-                    dest_size: if let EmployeeState::Falling = e.get_state() {
-                        let scale = 100.0
-                            - (e.get_pos().y - MIDDLE_LANE) / (OFFICE_HEIGHT as f32 - MIDDLE_LANE)
-                                * 100.
-                            + 50.;
-                        Some(Vec2::new(scale, scale))
-                    } else {
-                        Some(Vec2::new(100.0, 100.0))
-                    },
-                    ..Default::default()
+                e.get_rotation(),
+                if matches!(e.get_state(), EmployeeState::Falling) {
+                    let scale = ((e.get_pos().y - MIDDLE_LANE)
+                        / (OFFICE_HEIGHT as f32 - MIDDLE_LANE))
+                        .mul_add(-100., 100.0)
+                        + 50.;
+                    Vec2::new(scale, scale)
+                } else {
+                    Vec2::new(100.0, 100.0)
                 },
             );
 
@@ -515,32 +1110,34 @@ impl Drawing {
             }
         }
 
-        // Draw fire on broken computers
-        game.get_office()
-            .iter_computers_mut()
-            .filter(|c| c.borrow().broken)
-            .for_each(|c| {
-                let mut computer = c.borrow_mut();
-                let pos = computer.position;
-                computer.emitter.draw(pos);
-            });
+        // Draw the fires
+        for mut fire in game.get_office().iter_fires_mut() {
+            let pos = fire.get_pos();
+            draw_circle(
+                pos.x,
+                pos.y,
+                fire.get_radius(),
+                Color::new(1., 0.3, 0., 0.15),
+            );
+            fire.emitter.draw(pos);
+        }
     }
 
     pub fn draw_office(&mut self, game: &Game) {
-        set_camera(&self.camera_office);
+        if self.follow_selected {
+            if let Some(selected) = game.get_office().get_selected_employee() {
+                let target = selected.borrow().get_pos();
+                self.office_center = self.office_center.lerp(target, FOLLOW_SPEED);
+                self.clamp_office_center();
+            }
+        }
+
+        let mut camera = Camera2D::from_display_rect(self.office_view_rect());
+        camera.render_target = Some(self.render_target_office.clone());
+        set_camera(&camera);
         clear_background(WHITE);
-        draw_texture_ex(
-            &assets::OFFICE_TEXTURE,
-            0.,
-            0.,
-            WHITE,
-            DrawTextureParams {
-                source: None,
-                rotation: 0.0,
-                dest_size: Some(Vec2::new(1280.0, 720.0)),
-                ..Default::default()
-            },
-        );
+        Self::draw_layout(game.get_office().get_layout());
+        Self::draw_season(game.get_office().get_day());
 
         if let DoorState::Closed = game.get_office().get_door_state() {
             if self.door_rotation < 0.0 {
@@ -554,11 +1151,18 @@ impl Drawing {
             } else {
                 self.door_rotation = -PI / 2.
             }
+        } else if matches!(game.get_office().get_door_state(), DoorState::Broken) {
+            // Hanging from a single hinge
+            self.door_rotation = -PI / 3.;
         }
 
         draw_texture_ex(
-            &assets::DOOR_TEXTURE,
-            340.,
+            if matches!(game.get_office().get_door_state(), DoorState::Broken) {
+                &assets::DOOR_BROKEN_TEXTURE
+            } else {
+                &assets::DOOR_TEXTURE
+            },
+            340.,
             320.,
             WHITE,
             DrawTextureParams {
@@ -604,6 +1208,7 @@ impl Drawing {
                 ..Default::default()
             },
         );
+        Self::draw_window_state(game.get_office().get_window_state());
 
         // Draw the computers
         for c in game.get_office().iter_computers_mut() {
@@ -618,36 +1223,116 @@ impl Drawing {
                 texture,
                 computer.position.x - 25.0,
                 computer.position.y - 25.0,
-                WHITE,
+                computer.tier.get_color(),
                 DrawTextureParams {
                     rotation: computer.rotation,
                     dest_size: Some(Vec2::new(50.0, 50.0)),
                     ..Default::default()
                 },
             );
+
+            if computer.being_repaired {
+                draw_rectangle(
+                    computer.position.x - 25.,
+                    computer.position.y - 40.,
+                    50.,
+                    8.,
+                    DARKGRAY,
+                );
+                draw_rectangle(
+                    computer.position.x - 25.,
+                    computer.position.y - 40.,
+                    50. * computer.repair_progress,
+                    8.,
+                    GREEN,
+                );
+            }
+
+            if game
+                .get_office()
+                .get_selected_computer()
+                .is_some_and(|selected| Rc::ptr_eq(selected, &c))
+            {
+                draw_circle_lines(
+                    computer.position.x,
+                    computer.position.y,
+                    COMPUTER_RADIUS,
+                    4.,
+                    YELLOW,
+                );
+            }
         }
 
+        Self::draw_zones(game.get_office());
+        Self::draw_technicians(game.get_office());
         self.handle_passer();
 
+        // Draw the relationships and the path of the selected employee
+        if let Some(selected) = game.get_office().get_selected_employee() {
+            let selected = selected.borrow();
+
+            let mut previous = selected.get_pos();
+            for &point in selected.get_path() {
+                draw_line(
+                    previous.x,
+                    previous.y,
+                    point.x,
+                    point.y,
+                    3.,
+                    Color::new(1., 1., 1., 0.4),
+                );
+                previous = point;
+            }
+
+            for (id, kind) in game
+                .get_office()
+                .get_relationships()
+                .iter_of(selected.get_id())
+            {
+                if let Some(other) = game
+                    .get_office()
+                    .iter_employees()
+                    .find(|e| e.get_id() == id)
+                {
+                    draw_line(
+                        selected.get_pos().x,
+                        selected.get_pos().y,
+                        other.get_pos().x,
+                        other.get_pos().y,
+                        6.,
+                        match kind {
+                            RelationshipKind::Friend => Color::new(0., 0.8, 0.2, 0.6),
+                            RelationshipKind::Rival => Color::new(0.9, 0.1, 0.1, 0.6),
+                        },
+                    );
+                }
+            }
+        }
+
+        for selected in game.get_office().get_selected_employees() {
+            let pos = selected.borrow().get_pos();
+            draw_circle_lines(pos.x, pos.y, EMPLOYEE_RADIUS, 4., YELLOW);
+        }
+
         // Draw employees
         for mut e in game.get_office().iter_employees_mut() {
-            draw_texture_ex(
-                &assets::EMPLOYEE_TEXTURE,
+            if matches!(e.get_state(), EmployeeState::Home) {
+                continue;
+            }
+
+            Self::draw_employee_sprite(
+                e.get_identity(),
                 e.get_pos().x - EMPLOYEE_RADIUS,
                 e.get_pos().y - EMPLOYEE_RADIUS,
-                WHITE,
-                DrawTextureParams {
-                    rotation: e.get_rotation(),
-                    dest_size: if let EmployeeState::Falling = e.get_state() {
-                        let scale = 100.0
-                            - (e.get_pos().y - MIDDLE_LANE) / (OFFICE_HEIGHT as f32 - MIDDLE_LANE)
-                                * 100.
-                            + 50.;
-                        Some(Vec2::new(scale, scale))
-                    } else {
-                        Some(Vec2::new(100.0, 100.0))
-                    },
-                    ..Default::default()
+                e.get_rotation(),
+                if matches!(e.get_state(), EmployeeState::Falling) {
+                    let scale = ((e.get_pos().y - MIDDLE_LANE)
+                        / (OFFICE_HEIGHT as f32 - MIDDLE_LANE))
+                        .mul_add(-100., 100.0)
+                        + 50.;
+                    Vec2::new(scale, scale)
+                } else {
+                    Vec2::new(100.0, 100.0)
                 },
             );
 
@@ -696,15 +1381,127 @@ impl Drawing {
             }
         }
 
-        // Draw fire on broken computers
-        game.get_office()
-            .iter_computers_mut()
-            .filter(|c| c.borrow().broken)
-            .for_each(|c| {
-                let mut computer = c.borrow_mut();
-                let pos = computer.position;
-                computer.emitter.draw(pos);
-            });
+        // Draw the fires
+        for mut fire in game.get_office().iter_fires_mut() {
+            let pos = fire.get_pos();
+            draw_circle(
+                pos.x,
+                pos.y,
+                fire.get_radius(),
+                Color::new(1., 0.3, 0., 0.15),
+            );
+            fire.emitter.draw(pos);
+        }
+
+        let mouse = self.convert_screen_office(vec2(mouse_position().0, mouse_position().1));
+        if let Some(rect) = self.get_selection_rect(mouse) {
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, Color::new(1., 1., 0., 0.15));
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2., YELLOW);
+        }
+
+        // The tabs and buttons do not move with the camera
+        set_camera(&self.camera_office);
+        Self::draw_floor_tabs(game.get_company());
+
+        let rect = Self::follow_button_rect();
+        draw_rectangle(
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+            if self.follow_selected {
+                YELLOW
+            } else {
+                LIGHTGRAY
+            },
+        );
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 4., BLACK);
+        draw_text_ex(
+            "Suivre",
+            rect.x + 35.,
+            rect.y + 33.,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: 24,
+                color: BLACK,
+                ..Default::default()
+            },
+        );
+
+        for (i, filter) in SelectionFilter::ALL.into_iter().enumerate() {
+            let rect = Self::filter_button_rect(i);
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, LIGHTGRAY);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 4., BLACK);
+
+            let dimensions = measure_text(filter.get_name(), Some(&assets::FONT), 24, 1.);
+            draw_text_ex(
+                filter.get_name(),
+                rect.x + (rect.w - dimensions.width) / 2.,
+                rect.y + 33.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 24,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    /// Tab of a floor, the one after the last floor buys a new one
+    pub const fn floor_tab_rect(index: usize) -> Rect {
+        Rect::new((index as f32).mul_add(150., 360.), 15., 140., 50.)
+    }
+
+    fn draw_floor_tabs(company: &Company) {
+        for i in 0..company.floors_count() {
+            let rect = Self::floor_tab_rect(i);
+            let current = i == company.get_current_index();
+
+            draw_rectangle(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                if current { YELLOW } else { LIGHTGRAY },
+            );
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 4., BLACK);
+            draw_text_ex(
+                format!("Étage {}", i + 1),
+                rect.x + 25.,
+                rect.y + 33.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 24,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+        }
+
+        if company.floors_count() < MAX_FLOORS {
+            let rect = Self::floor_tab_rect(company.floors_count());
+
+            draw_rectangle(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                if company.can_buy_floor() { GREEN } else { RED },
+            );
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 4., BLACK);
+            draw_text_ex(
+                format!("+ {}", company.get_floor_price()),
+                rect.x + 25.,
+                rect.y + 33.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 24,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+        }
     }
 
     pub fn draw_frame(&self) {
@@ -773,7 +1570,6 @@ impl Drawing {
     fn draw_info(&self, game: &Game) {
         set_camera(&self.camera_info);
         clear_background(WHITE);
-        let main_pos = Drawing::convert_screen_main(vec2(mouse_position().0, mouse_position().1));
 
         if let Some(qte) = game.get_qte_ongoing() {
             self.draw_frame_qte();
@@ -862,69 +1658,541 @@ impl Drawing {
         } else if let Some(answer) = game.get_answer() {
             self.draw_frame_qte();
             self.draw_info_text(answer.clone());
-        } else {
-            if self.rect_personnal_stat.contains(main_pos) {
-                if let Some(employee) = game.get_office().get_selected_employee() {
-                    match employee.borrow().get_state() {
-                        EmployeeState::Alive => {
-                            let stat_pos = Self::convert_main_personnal_stat(main_pos);
-                            if self.button_personnal_energy.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_BUTTON_ENERGY.to_string());
-                            } else if self.button_personnal_hope.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_BUTTON_HOPE.to_string());
-                            } else if self.button_personnal_satiety.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_BUTTON_SATIETY.to_string());
-                            } else if self.button_personnal_satisfaction.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_BUTTON_SATISFACTION.to_string());
-                            } else if self.bar_energy.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_ENERGY.to_string());
-                            } else if self.bar_hope.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_HOPE.to_string());
-                            } else if self.bar_satiety.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_SATIETY.to_string());
-                            } else if self.bar_satisfaction.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_SATISFACTION.to_string());
-                            }
-                        }
-                        EmployeeState::Dead => {
-                            let stat_pos = Self::convert_main_personnal_stat(main_pos);
-                            if self.button_personnal_satisfaction.contains(stat_pos) {
-                                self.draw_frame();
-                                self.draw_info_text(DESCRIPTION_BUTTON_CLEAN.to_string());
-                            }
-                        }
-                        EmployeeState::Falling
-                        | EmployeeState::Clean
-                        | EmployeeState::Suicide
-                        | EmployeeState::Arriving => (),
-                    }
+        } else if self.show_memorial {
+            self.draw_frame();
+            Self::draw_memorial(game.get_office().get_memorial());
+        } else if self.show_policies {
+            self.draw_frame();
+            Self::draw_policies(game.get_office());
+        }
+    }
+
+    /// Description of the button or bar under the mouse
+    fn get_button_tooltip(&self, game: &Game, screen_pos: Vec2) -> Option<String> {
+        let office = game.get_office();
+
+        if self.get_rect_personnal_stat().contains(screen_pos) {
+            let stat_pos = Self::convert_screen_personnal_stat(screen_pos);
+
+            if office.get_selected_computer().is_some() {
+                return [
+                    (self.button_computer_repair, DESCRIPTION_BUTTON_REPAIR),
+                    (self.button_computer_replace, DESCRIPTION_BUTTON_REPLACE),
+                    (self.button_computer_upgrade, DESCRIPTION_BUTTON_UPGRADE),
+                    (
+                        self.button_computer_extinguish,
+                        DESCRIPTION_BUTTON_EXTINGUISH,
+                    ),
+                ]
+                .into_iter()
+                .find(|(rect, _)| rect.contains(stat_pos))
+                .map(|(_, description)| description.to_owned());
+            }
+
+            let action_buttons = [
+                (self.button_personnal_energy, DESCRIPTION_BUTTON_ENERGY),
+                (self.button_personnal_hope, DESCRIPTION_BUTTON_HOPE),
+                (self.button_personnal_satiety, DESCRIPTION_BUTTON_SATIETY),
+                (
+                    self.button_personnal_satisfaction,
+                    DESCRIPTION_BUTTON_SATISFACTION,
+                ),
+                (self.bar_energy, DESCRIPTION_ENERGY),
+                (self.bar_hope, DESCRIPTION_HOPE),
+                (self.bar_satiety, DESCRIPTION_SATIETY),
+                (self.bar_satisfaction, DESCRIPTION_SATISFACTION),
+            ];
+
+            let Some(employee) = office.get_selected_employee() else {
+                if office.get_selected_employees().is_empty() {
+                    return None;
+                }
+
+                return action_buttons
+                    .into_iter()
+                    .find(|(rect, _)| rect.contains(stat_pos))
+                    .map(|(_, description)| description.to_owned());
+            };
+            let employee = employee.borrow();
+
+            if self.button_personnal_history.contains(stat_pos) {
+                return Some(DESCRIPTION_BUTTON_HISTORY.to_owned());
+            }
+            if game.get_company().floors_count() > 1
+                && self.button_personnal_transfer.contains(stat_pos)
+            {
+                return Some(DESCRIPTION_BUTTON_TRANSFER.to_owned());
+            }
+
+            if let Some((_, t)) = employee
+                .get_personality()
+                .iter_traits()
+                .enumerate()
+                .find(|(i, _)| Self::trait_badge_rect(*i).contains(stat_pos))
+            {
+                return Some(t.get_description().to_owned());
+            }
+
+            let buttons = match employee.get_state() {
+                EmployeeState::Alive => action_buttons.to_vec(),
+                EmployeeState::Dead => {
+                    vec![(self.button_personnal_satisfaction, DESCRIPTION_BUTTON_CLEAN)]
                 }
+                EmployeeState::Falling
+                | EmployeeState::Clean
+                | EmployeeState::Suicide
+                | EmployeeState::Arriving
+                | EmployeeState::Home => vec![],
+            };
+
+            return buttons
+                .into_iter()
+                .find(|(rect, _)| rect.contains(stat_pos))
+                .map(|(_, description)| description.to_owned());
+        }
+
+        if self.get_rect_global_stat().contains(screen_pos) {
+            let global_pos = Self::convert_screen_global_stat(screen_pos);
+
+            if self
+                .tool_buttons()
+                .into_iter()
+                .any(|(tool, rect)| rect.contains(global_pos) && !game.is_tool_allowed(tool))
+            {
+                return Some(DESCRIPTION_LOCKED_TOOL.to_owned());
+            }
+
+            if self.button_global_door.contains(global_pos) {
+                return Some(if matches!(office.get_door_state(), DoorState::Broken) {
+                    format!("{DESCRIPTION_BUTTON_DOOR_BROKEN} ({DOOR_REPAIR_COST})")
+                } else {
+                    DESCRIPTION_BUTTON_DOOR.to_owned()
+                });
+            } else if self.button_global_meth.contains(global_pos) {
+                return Some(format!(
+                    "{} {}",
+                    DESCRIPTION_BUTTON_METH,
+                    office.get_rules().meth_cost
+                ));
+            } else if self.button_global_rh.contains(global_pos) {
+                return Some(format!(
+                    "{} {}",
+                    DESCRIPTION_BUTTON_RH,
+                    office.get_rules().rh_cost
+                ));
+            } else if self.button_global_window.contains(global_pos) {
+                return Some(match office.get_window_state() {
+                    WindowState::Unlocked => {
+                        format!("{DESCRIPTION_BUTTON_WINDOW_UNLOCKED} ({WINDOW_LOCK_COST})")
+                    }
+                    WindowState::Locked => {
+                        format!("{DESCRIPTION_BUTTON_WINDOW_LOCKED} ({WINDOW_BARRICADE_COST})")
+                    }
+                    WindowState::Barricaded => DESCRIPTION_BUTTON_WINDOW_BARRICADED.to_owned(),
+                });
+            } else if self.button_global_memorial.contains(global_pos) {
+                return Some(DESCRIPTION_BUTTON_MEMORIAL.to_owned());
+            } else if self.button_global_policies.contains(global_pos) {
+                return Some(DESCRIPTION_BUTTON_POLICIES.to_owned());
+            }
+        }
+
+        if self.get_rect_office().contains(screen_pos) {
+            let company = game.get_company();
+            let office_pos = Self::convert_screen_office_view(screen_pos);
+
+            if company.floors_count() < MAX_FLOORS
+                && Self::floor_tab_rect(company.floors_count()).contains(office_pos)
+            {
+                return Some(format!(
+                    "{} ({})",
+                    DESCRIPTION_BUTTON_FLOOR,
+                    company.get_floor_price()
+                ));
+            } else if Self::follow_button_rect().contains(office_pos) {
+                return Some(DESCRIPTION_BUTTON_FOLLOW.to_owned());
+            } else if let Some(filter) = SelectionFilter::ALL
+                .into_iter()
+                .enumerate()
+                .find(|(i, _)| Self::filter_button_rect(*i).contains(office_pos))
+                .map(|(_, filter)| filter)
+            {
+                return Some(format!(
+                    "{} {}",
+                    DESCRIPTION_BUTTON_FILTER,
+                    filter.get_name().to_lowercase()
+                ));
             }
+        }
+
+        None
+    }
+
+    /// Tooltip of the button or of the employee under the mouse, drawn over
+    /// the panels
+    /// Banner at the top of the screen for the achievement just unlocked
+    fn draw_achievement_toast(game: &Game) {
+        let Some(achievement) = game.get_achievements().get_toast() else {
+            return;
+        };
+
+        let title = format!("Succès débloqué : {}", achievement.get_name());
+        let description = achievement.get_description();
+        let width = TOOLTIP_PADDING.mul_add(
+            2.,
+            [&title, &description]
+                .iter()
+                .map(|text| measure_text(text, Some(&assets::FONT), TOOLTIP_FONT_SIZE, 1.).width)
+                .fold(0., f32::max),
+        );
+        let height = f32::from(TOOLTIP_FONT_SIZE).mul_add(2.6, TOOLTIP_PADDING * 2.);
+        let pos = vec2((screen_width() - width) / 2., TOOLTIP_OFFSET);
+
+        draw_rectangle(pos.x, pos.y, width, height, Color::new(1., 0.95, 0.7, 0.95));
+        draw_rectangle_lines(pos.x, pos.y, width, height, 2., GOLD);
+        draw_text_ex(
+            &title,
+            pos.x + TOOLTIP_PADDING,
+            pos.y + TOOLTIP_PADDING + f32::from(TOOLTIP_FONT_SIZE),
+            Self::tooltip_params(BLACK),
+        );
+        draw_text_ex(
+            &description,
+            pos.x + TOOLTIP_PADDING,
+            f32::from(TOOLTIP_FONT_SIZE).mul_add(2.3, pos.y + TOOLTIP_PADDING),
+            Self::tooltip_params(DARKGRAY),
+        );
+    }
+
+    fn draw_tooltips(&self, game: &Game) {
+        let screen_pos = vec2(mouse_position().0, mouse_position().1);
+
+        if let Some(text) = self.get_button_tooltip(game, screen_pos) {
+            Self::draw_text_tooltip(&text, screen_pos);
+        } else if self.get_rect_office().contains(screen_pos) {
+            let office = game.get_office();
+
+            if let Some(employee) = office.employee_at(self.convert_screen_office(screen_pos)) {
+                Self::draw_employee_tooltip(&employee.borrow(), office, screen_pos);
+            }
+        }
+    }
+
+    fn tooltip_params(color: Color) -> TextParams<'static> {
+        TextParams {
+            font: Some(&assets::FONT),
+            font_size: TOOLTIP_FONT_SIZE,
+            color,
+            ..Default::default()
+        }
+    }
+
+    /// Splits the text in lines that fit in the tooltip
+    fn wrap_tooltip_text(text: &str) -> Vec<String> {
+        Self::wrap_text(text, TOOLTIP_FONT_SIZE, TOOLTIP_WIDTH)
+    }
+
+    /// Splits the text in lines no wider than `max_width`
+    fn wrap_text(text: &str, font_size: u16, max_width: f32) -> Vec<String> {
+        let mut lines = vec![];
+        let mut line = String::new();
+
+        for word in text.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_owned()
+            } else {
+                format!("{line} {word}")
+            };
+
+            let width = measure_text(&candidate, Some(&assets::FONT), font_size, 1.).width;
+            if width > max_width && !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+                line.push_str(word);
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+
+        lines
+    }
 
-            if self.rect_global_stat.contains(main_pos) {
-                let global_pos = Self::convert_main_global_stat(main_pos);
-                if self.button_global_door.contains(global_pos) {
-                    self.draw_frame();
-                    self.draw_info_text(DESCRIPTION_BUTTON_DOOR.to_string());
-                } else if self.button_global_meth.contains(global_pos) {
-                    self.draw_frame();
-                    self.draw_info_text(DESCRIPTION_BUTTON_METH.to_string());
-                } else if self.button_global_rh.contains(global_pos) {
-                    self.draw_frame();
-                    self.draw_info_text(DESCRIPTION_BUTTON_RH.to_string());
+    /// Draws the background of a tooltip next to the mouse, kept inside the
+    /// screen, and returns where its content starts
+    fn draw_tooltip_frame(screen_pos: Vec2, content_height: f32) -> Vec2 {
+        let size = vec2(TOOLTIP_WIDTH, content_height) + TOOLTIP_PADDING * 2.;
+        let mut pos = screen_pos + TOOLTIP_OFFSET;
+
+        if pos.x + size.x > screen_width() {
+            pos.x = screen_pos.x - TOOLTIP_OFFSET - size.x;
+        }
+        if pos.y + size.y > screen_height() {
+            pos.y = screen_pos.y - TOOLTIP_OFFSET - size.y;
+        }
+        let pos = pos.max(Vec2::ZERO);
+
+        draw_rectangle(pos.x, pos.y, size.x, size.y, Color::new(1., 1., 1., 0.95));
+        draw_rectangle_lines(pos.x, pos.y, size.x, size.y, 2., BLACK);
+
+        pos + TOOLTIP_PADDING
+    }
+
+    fn draw_text_tooltip(text: &str, screen_pos: Vec2) {
+        let lines = Self::wrap_tooltip_text(text);
+        let line_height = f32::from(TOOLTIP_FONT_SIZE) * 1.2;
+
+        let pos = Self::draw_tooltip_frame(screen_pos, lines.len() as f32 * line_height);
+
+        for (i, line) in lines.iter().enumerate() {
+            draw_text_ex(
+                line,
+                pos.x,
+                (i as f32).mul_add(line_height, pos.y + f32::from(TOOLTIP_FONT_SIZE)),
+                Self::tooltip_params(BLACK),
+            );
+        }
+    }
+
+    /// Name, state, action and stats of the employee, and what will happen
+    /// to them if nothing changes
+    fn draw_employee_tooltip(employee: &Employee, office: &Office, screen_pos: Vec2) {
+        let line_height = f32::from(TOOLTIP_FONT_SIZE) * 1.3;
+        let state = employee.get_state();
+
+        let status = if matches!(state, EmployeeState::Alive) {
+            format!("{} - {}", state.get_name(), employee.action.get_name())
+        } else {
+            state.get_name().to_owned()
+        };
+
+        let prediction = employee
+            .get_prediction(*office.get_door_state(), office.get_rules())
+            .map(|(prediction, ticks)| {
+                let seconds = ticks / FPS;
+
+                if seconds < 60. {
+                    format!("{} dans ~{:.0}s", prediction.get_text(), seconds.max(1.))
+                } else {
+                    format!("{} dans ~{:.0}min", prediction.get_text(), seconds / 60.)
                 }
+            });
+
+        let lines = 6 + usize::from(prediction.is_some());
+        let pos = Self::draw_tooltip_frame(screen_pos, lines as f32 * line_height);
+        let baseline =
+            |line: usize| (line as f32).mul_add(line_height, pos.y + f32::from(TOOLTIP_FONT_SIZE));
+
+        draw_text_ex(
+            employee.get_name(),
+            pos.x,
+            baseline(0),
+            TextParams {
+                font_size: TOOLTIP_FONT_SIZE + 4,
+                ..Self::tooltip_params(BLACK)
+            },
+        );
+        draw_text_ex(&status, pos.x, baseline(1), Self::tooltip_params(DARKGRAY));
+
+        for (i, (stat, value)) in employee.get_stats().into_iter().enumerate() {
+            let y = baseline(2 + i);
+            let color = match stat {
+                Stat::Satisfaction => RED,
+                Stat::Energy => YELLOW,
+                Stat::Satiety => BLUE,
+                Stat::Hope => GREEN,
+            };
+
+            draw_text_ex(stat.get_name(), pos.x, y, Self::tooltip_params(BLACK));
+
+            let bar = Rect::new(
+                TOOLTIP_WIDTH.mul_add(0.35, pos.x),
+                f32::from(TOOLTIP_FONT_SIZE).mul_add(-0.7, y),
+                TOOLTIP_WIDTH * 0.65,
+                f32::from(TOOLTIP_FONT_SIZE) * 0.7,
+            );
+            draw_rectangle(bar.x, bar.y, bar.w, bar.h, LIGHTGRAY);
+            draw_rectangle(bar.x, bar.y, bar.w * value, bar.h, color);
+            draw_rectangle_lines(bar.x, bar.y, bar.w, bar.h, 1., BLACK);
+        }
+
+        if let Some(prediction) = prediction {
+            draw_text_ex(&prediction, pos.x, baseline(6), Self::tooltip_params(RED));
+        }
+    }
+
+    /// The rules of the office, and the ones that can be added
+    fn draw_policies(office: &Office) {
+        let params = |font_size: u16, color: Color| TextParams {
+            font: Some(&assets::FONT),
+            font_size,
+            color,
+            ..Default::default()
+        };
+
+        let policies = office.get_policies();
+
+        draw_text_ex("Règles", 200., 300., params(120, BLACK));
+        draw_text_ex(
+            format!("Coût : {} par jour", policies.get_upkeep()),
+            200.,
+            400.,
+            params(60, DARKGRAY),
+        );
+
+        for (i, policy) in policies.iter().enumerate() {
+            draw_text_ex(
+                policy.describe(),
+                200.,
+                Self::policy_row_y(i),
+                params(45, if policy.enabled { BLACK } else { GRAY }),
+            );
+
+            if let Trigger::StatBelow(_) = policy.trigger {
+                Self::draw_text_button(
+                    Self::policy_button_rect(i, PolicyButton::Lower),
+                    LIGHTGRAY,
+                    "-",
+                    60,
+                );
+                Self::draw_text_button(
+                    Self::policy_button_rect(i, PolicyButton::Raise),
+                    LIGHTGRAY,
+                    "+",
+                    60,
+                );
+            }
+            Self::draw_text_button(
+                Self::policy_button_rect(i, PolicyButton::Toggle),
+                if policy.enabled { GREEN } else { LIGHTGRAY },
+                if policy.enabled { "Oui" } else { "Non" },
+                45,
+            );
+            Self::draw_text_button(
+                Self::policy_button_rect(i, PolicyButton::Remove),
+                RED,
+                "X",
+                50,
+            );
+        }
+
+        let target = Self::get_policy_target(office);
+        draw_text_ex(
+            format!(
+                "Ajouter pour {} ({} par jour) :",
+                target.get_name(),
+                target.get_upkeep()
+            ),
+            200.,
+            1440.,
+            params(55, BLACK),
+        );
+
+        for (i, (action, trigger)) in TEMPLATES.into_iter().enumerate() {
+            let rect = Self::policy_template_rect(i);
+            let color = if policies.is_full() { LIGHTGRAY } else { GREEN };
+
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 10., BLACK);
+            draw_text_ex(
+                describe_rule(action, trigger),
+                rect.x + 30.,
+                rect.y + 65.,
+                params(50, BLACK),
+            );
+        }
+    }
+
+    /// New rules are for the selected employee, or for everyone
+    pub fn get_policy_target(office: &Office) -> PolicyTarget {
+        office
+            .get_selected_employee()
+            .map_or(PolicyTarget::Everyone, |employee| {
+                let employee = employee.borrow();
+                PolicyTarget::Employee(employee.get_id(), employee.get_name().to_owned())
+            })
+    }
+
+    fn draw_memorial(memorial: &[MemorialEntry]) {
+        let params = |font_size: u16, color: Color| TextParams {
+            font: Some(&assets::FONT),
+            font_size,
+            color,
+            ..Default::default()
+        };
+
+        draw_text_ex("Mémorial", 200., 300., params(120, BLACK));
+
+        if memorial.is_empty() {
+            draw_text_ex(
+                "Personne... pour l'instant.",
+                200.,
+                450.,
+                params(60, DARKGRAY),
+            );
+            return;
+        }
+
+        let mut y = 450.;
+
+        for entry in memorial.iter().rev() {
+            if y > INFO_HEIGHT as f32 - 250. {
+                break;
+            }
+
+            draw_text_ex(
+                format!(
+                    "{} - {} le jour {}",
+                    entry.name,
+                    entry.fate.describe(),
+                    entry.day
+                ),
+                200.,
+                y,
+                params(60, BLACK),
+            );
+            y += 70.;
+
+            for event in entry.history.iter().rev().skip(1).take(3) {
+                draw_text_ex(event.describe(), 260., y, params(45, DARKGRAY));
+                y += 55.;
             }
+            y += 30.;
+        }
+    }
+
+    fn draw_history(history: &[HistoryEntry]) {
+        for (i, entry) in history.iter().rev().take(11).enumerate() {
+            draw_text_ex(
+                entry.describe(),
+                20.,
+                (i as f32).mul_add(62., 230.),
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 50,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
         }
     }
 
+    fn draw_text_button(rect: Rect, color: Color, text: &str, font_size: u16) {
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 10., BLACK);
+        let dimensions = measure_text(text, Some(&assets::FONT), font_size, 1.);
+        draw_text_ex(
+            text,
+            rect.x + (rect.w - dimensions.width) / 2.,
+            rect.y + f32::midpoint(rect.h, dimensions.offset_y),
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size,
+                color: BLACK,
+                ..Default::default()
+            },
+        );
+    }
+
     fn draw_button(rect: Rect, color: Color, texture: &Texture2D) {
         draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
         draw_texture_ex(
@@ -986,23 +2254,185 @@ impl Drawing {
         );
     }
 
-    fn draw_personnal_stat(&mut self, game: &Game) {
-        fn draw_bar(rect: Rect) {
-            draw_rectangle(rect.x, rect.y, rect.w, rect.h, LIGHTGRAY);
-            draw_rectangle_lines(
-                rect.x,
-                rect.y,
-                rect.w,
-                rect.h,
-                PERSONNAL_LINES_THICKNES,
-                BLACK,
-            );
+    fn draw_empty_bar(rect: Rect) {
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, LIGHTGRAY);
+        draw_rectangle_lines(
+            rect.x,
+            rect.y,
+            rect.w,
+            rect.h,
+            PERSONNAL_LINES_THICKNES,
+            BLACK,
+        );
+    }
+
+    /// The four stat bars, filled up to the values
+    fn draw_stat_bars(&mut self, satisfaction: f32, energy: f32, hope: f32, satiety: f32) {
+        Self::draw_empty_bar(self.bar_satisfaction);
+        Self::draw_empty_bar(self.bar_energy);
+        Self::draw_empty_bar(self.bar_hope);
+        Self::draw_empty_bar(self.bar_satiety);
+
+        self.draw_bar_name();
+
+        self.displayed_satisfaction = lerp(
+            self.displayed_satisfaction,
+            satisfaction * (self.bar_satisfaction.w - PERSONNAL_LINES_THICKNES),
+            ANIMATION_SPEED,
+        );
+
+        self.displayed_energy = lerp(
+            self.displayed_energy,
+            energy * (self.bar_energy.w - PERSONNAL_LINES_THICKNES),
+            ANIMATION_SPEED,
+        );
+
+        self.displayed_hope = lerp(
+            self.displayed_hope,
+            hope * (self.bar_hope.w - PERSONNAL_LINES_THICKNES),
+            ANIMATION_SPEED,
+        );
+
+        self.displayed_satiety = lerp(
+            self.displayed_satiety,
+            satiety * (self.bar_satiety.w - PERSONNAL_LINES_THICKNES),
+            ANIMATION_SPEED,
+        );
+
+        draw_rectangle(
+            self.bar_satisfaction.x + PERSONNAL_LINES_THICKNES / 2.,
+            self.bar_satisfaction.y + PERSONNAL_LINES_THICKNES / 2.,
+            self.displayed_satisfaction,
+            self.bar_satisfaction.h - PERSONNAL_LINES_THICKNES,
+            RED,
+        );
+
+        draw_rectangle(
+            self.bar_energy.x + PERSONNAL_LINES_THICKNES / 2.,
+            self.bar_energy.y + PERSONNAL_LINES_THICKNES / 2.,
+            self.displayed_energy,
+            self.bar_energy.h - PERSONNAL_LINES_THICKNES,
+            YELLOW,
+        );
+
+        draw_rectangle(
+            self.bar_satiety.x + PERSONNAL_LINES_THICKNES / 2.,
+            self.bar_satiety.y + PERSONNAL_LINES_THICKNES / 2.,
+            self.displayed_satiety,
+            self.bar_satiety.h - PERSONNAL_LINES_THICKNES,
+            BLUE,
+        );
+
+        draw_rectangle(
+            self.bar_hope.x + PERSONNAL_LINES_THICKNES / 2.,
+            self.bar_hope.y + PERSONNAL_LINES_THICKNES / 2.,
+            self.displayed_hope,
+            self.bar_hope.h - PERSONNAL_LINES_THICKNES,
+            GREEN,
+        );
+    }
+
+    /// Color of an action button for a group: green if nobody does the
+    /// action, red if everyone is doing it, orange otherwise
+    fn group_action_color(employees: &[Rc<RefCell<Employee>>], action: EmployeeAction) -> Color {
+        let alive = employees
+            .iter()
+            .map(|e| e.borrow())
+            .filter(|e| matches!(e.get_state(), EmployeeState::Alive))
+            .collect::<Vec<_>>();
+
+        if alive.iter().all(|e| e.action != action) {
+            GREEN
+        } else if alive
+            .iter()
+            .all(|e| e.action == action && e.is_using_zone())
+        {
+            RED
+        } else {
+            ORANGE
         }
+    }
+
+    /// Average stats of the selected employees, and the actions for all of them
+    fn draw_group_panel(&mut self, employees: &[Rc<RefCell<Employee>>]) {
+        let alive = employees
+            .iter()
+            .map(|e| e.borrow())
+            .filter(|e| matches!(e.get_state(), EmployeeState::Alive))
+            .collect::<Vec<_>>();
 
+        draw_text_ex(
+            format!("{} employés", employees.len()),
+            0.,
+            FONT_SIZE_PERSONNAL,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: FONT_SIZE_PERSONNAL as u16,
+                color: BLACK,
+                ..Default::default()
+            },
+        );
+        draw_text_ex(
+            format!("Moyenne des {} en vie", alive.len()),
+            10.,
+            FONT_SIZE_PERSONNAL + 55.,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: 40,
+                color: DARKGRAY,
+                ..Default::default()
+            },
+        );
+
+        let count = alive.len().max(1) as f32;
+        let average =
+            |stat: fn(&Employee) -> f32| alive.iter().map(|e| stat(e)).sum::<f32>() / count;
+        let satisfaction = average(Employee::get_satisfaction);
+        let energy = average(Employee::get_energy);
+        let hope = average(Employee::get_hope);
+        let satiety = average(Employee::get_satiety);
+
+        self.draw_stat_bars(satisfaction, energy, hope, satiety);
+
+        for (rect, action, icon) in [
+            (
+                self.button_personnal_satiety,
+                EmployeeAction::Eat,
+                &*assets::ICON_SATIETY,
+            ),
+            (
+                self.button_personnal_energy,
+                EmployeeAction::Sleep,
+                &*assets::ICON_ENERGY,
+            ),
+            (
+                self.button_personnal_satisfaction,
+                EmployeeAction::Break,
+                &*assets::ICON_SATISFACTION,
+            ),
+            (
+                self.button_personnal_hope,
+                EmployeeAction::FamilyCall,
+                &*assets::ICON_HOPE,
+            ),
+        ] {
+            Self::draw_button(rect, Self::group_action_color(employees, action), icon);
+        }
+    }
+
+    fn draw_personnal_stat(&mut self, game: &Game) {
         set_camera(&self.camera_personnal_stat);
         clear_background(WHITE);
 
-        if let Some(selected_employee) = game.get_office().get_selected_employee() {
+        if let Some(computer) = game.get_office().get_selected_computer() {
+            self.draw_computer_panel(
+                &computer.borrow(),
+                game.get_office().is_burning(computer),
+                game.get_office().get_money(),
+            );
+        } else if game.get_office().get_selected_employees().len() > 1 {
+            self.draw_group_panel(game.get_office().get_selected_employees());
+        } else if let Some(selected_employee) = game.get_office().get_selected_employee() {
             draw_text_ex(
                 selected_employee.borrow().get_name(),
                 0.,
@@ -1023,12 +2453,86 @@ impl Drawing {
                 BLACK,
             );
 
+            let identity = selected_employee.borrow().get_identity().clone();
+
+            draw_text_ex(
+                format!(
+                    "{}, {} ans ({})",
+                    identity.gender.pronoun(),
+                    identity.age,
+                    identity.locale.country()
+                ),
+                10.,
+                FONT_SIZE_PERSONNAL + 55.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 40,
+                    color: DARKGRAY,
+                    ..Default::default()
+                },
+            );
+
+            for (i, t) in selected_employee
+                .borrow()
+                .get_personality()
+                .iter_traits()
+                .enumerate()
+            {
+                let rect = Self::trait_badge_rect(i);
+                draw_rectangle(rect.x, rect.y, rect.w, rect.h, t.get_color());
+                draw_text_ex(
+                    t.get_name(),
+                    rect.x + 10.,
+                    rect.y + rect.h - 7.,
+                    TextParams {
+                        font: Some(&assets::FONT),
+                        font_size: 26,
+                        color: BLACK,
+                        ..Default::default()
+                    },
+                );
+            }
+
+            // Portrait
+            Self::draw_employee_sprite(
+                &identity,
+                self.button_personnal_satisfaction.x + 60.,
+                0.,
+                -PI / 2.,
+                vec2(130., 130.),
+            );
+
+            Self::draw_text_button(
+                self.button_personnal_history,
+                if self.show_history { RED } else { GREEN },
+                "Histoire",
+                50,
+            );
+
+            if game.get_company().floors_count() > 1 {
+                Self::draw_text_button(
+                    self.button_personnal_transfer,
+                    if matches!(selected_employee.borrow().get_state(), EmployeeState::Alive) {
+                        GREEN
+                    } else {
+                        LIGHTGRAY
+                    },
+                    "Muter",
+                    50,
+                );
+            }
+
+            if self.show_history {
+                Self::draw_history(selected_employee.borrow().get_history());
+                return;
+            }
+
             match selected_employee.borrow().get_state() {
                 EmployeeState::Dead => {
-                    draw_bar(self.bar_satisfaction);
-                    draw_bar(self.bar_energy);
-                    draw_bar(self.bar_hope);
-                    draw_bar(self.bar_satiety);
+                    Self::draw_empty_bar(self.bar_satisfaction);
+                    Self::draw_empty_bar(self.bar_energy);
+                    Self::draw_empty_bar(self.bar_hope);
+                    Self::draw_empty_bar(self.bar_satiety);
 
                     self.draw_bar_name();
 
@@ -1041,110 +2545,44 @@ impl Drawing {
                     return;
                 }
                 EmployeeState::Alive => {
-                    draw_bar(self.bar_satisfaction);
-                    draw_bar(self.bar_energy);
-                    draw_bar(self.bar_hope);
-                    draw_bar(self.bar_satiety);
-
-                    self.draw_bar_name();
-
-                    self.displayed_satisfaction = lerp(
-                        self.displayed_satisfaction,
-                        selected_employee.as_ref().borrow().get_satisfaction()
-                            * (self.bar_satisfaction.w - PERSONNAL_LINES_THICKNES),
-                        ANIMATION_SPEED,
-                    );
-
-                    self.displayed_energy = lerp(
-                        self.displayed_energy,
-                        selected_employee.as_ref().borrow().get_energy()
-                            * (self.bar_energy.w - PERSONNAL_LINES_THICKNES),
-                        ANIMATION_SPEED,
-                    );
-
-                    self.displayed_hope = lerp(
-                        self.displayed_hope,
-                        selected_employee.as_ref().borrow().get_hope()
-                            * (self.bar_hope.w - PERSONNAL_LINES_THICKNES),
-                        ANIMATION_SPEED,
-                    );
-
-                    self.displayed_satiety = lerp(
-                        self.displayed_satiety,
-                        selected_employee.as_ref().borrow().get_satiety()
-                            * (self.bar_satiety.w - PERSONNAL_LINES_THICKNES),
-                        ANIMATION_SPEED,
-                    );
-
-                    draw_rectangle(
-                        self.bar_satisfaction.x + PERSONNAL_LINES_THICKNES / 2.,
-                        self.bar_satisfaction.y + PERSONNAL_LINES_THICKNES / 2.,
-                        self.displayed_satisfaction,
-                        self.bar_satisfaction.h - PERSONNAL_LINES_THICKNES,
-                        RED,
-                    );
-
-                    draw_rectangle(
-                        self.bar_energy.x + PERSONNAL_LINES_THICKNES / 2.,
-                        self.bar_energy.y + PERSONNAL_LINES_THICKNES / 2.,
-                        self.displayed_energy,
-                        self.bar_energy.h - PERSONNAL_LINES_THICKNES,
-                        YELLOW,
-                    );
-
-                    draw_rectangle(
-                        self.bar_satiety.x + PERSONNAL_LINES_THICKNES / 2.,
-                        self.bar_satiety.y + PERSONNAL_LINES_THICKNES / 2.,
-                        self.displayed_satiety,
-                        self.bar_satiety.h - PERSONNAL_LINES_THICKNES,
-                        BLUE,
-                    );
-
-                    draw_rectangle(
-                        self.bar_hope.x + PERSONNAL_LINES_THICKNES / 2.,
-                        self.bar_hope.y + PERSONNAL_LINES_THICKNES / 2.,
-                        self.displayed_hope,
-                        self.bar_hope.h - PERSONNAL_LINES_THICKNES,
-                        GREEN,
+                    let e = selected_employee.borrow();
+                    self.draw_stat_bars(
+                        e.get_satisfaction(),
+                        e.get_energy(),
+                        e.get_hope(),
+                        e.get_satiety(),
                     );
 
                     Drawing::draw_button(
                         self.button_personnal_satiety,
-                        if let EmployeeAction::Eat = selected_employee.borrow().action {
-                            RED
-                        } else {
-                            GREEN
-                        },
+                        Self::action_button_color(&selected_employee.borrow(), EmployeeAction::Eat),
                         &assets::ICON_SATIETY,
                     );
 
                     Drawing::draw_button(
                         self.button_personnal_energy,
-                        if let EmployeeAction::Sleep = selected_employee.borrow().action {
-                            RED
-                        } else {
-                            GREEN
-                        },
+                        Self::action_button_color(
+                            &selected_employee.borrow(),
+                            EmployeeAction::Sleep,
+                        ),
                         &assets::ICON_ENERGY,
                     );
 
                     Drawing::draw_button(
                         self.button_personnal_satisfaction,
-                        if let EmployeeAction::Break = selected_employee.borrow().action {
-                            RED
-                        } else {
-                            GREEN
-                        },
+                        Self::action_button_color(
+                            &selected_employee.borrow(),
+                            EmployeeAction::Break,
+                        ),
                         &assets::ICON_SATISFACTION,
                     );
 
                     Drawing::draw_button(
                         self.button_personnal_hope,
-                        if let EmployeeAction::FamilyCall = selected_employee.borrow().action {
-                            RED
-                        } else {
-                            GREEN
-                        },
+                        Self::action_button_color(
+                            &selected_employee.borrow(),
+                            EmployeeAction::FamilyCall,
+                        ),
                         &assets::ICON_HOPE,
                     );
                 }
@@ -1200,10 +2638,23 @@ impl Drawing {
                         },
                     );
                 }
+                EmployeeState::Home => {
+                    draw_text_ex(
+                        "À la maison",
+                        100.,
+                        300.,
+                        TextParams {
+                            font: Some(&assets::FONT),
+                            font_size: 100_u16,
+                            color: BLACK,
+                            ..Default::default()
+                        },
+                    );
+                }
             }
         } else {
             draw_text_ex(
-                "Sélectionnez un employé pour",
+                "Sélectionnez un employé ou",
                 100.,
                 300.,
                 TextParams {
@@ -1214,7 +2665,7 @@ impl Drawing {
                 },
             );
             draw_text_ex(
-                "voir ses informations.",
+                "un ordinateur.",
                 100.,
                 400.,
                 TextParams {
@@ -1230,11 +2681,38 @@ impl Drawing {
     fn draw_global_stat(&self, game: &Game) {
         set_camera(&self.camera_global_stat);
         clear_background(WHITE);
+
+        let day = game.get_office().get_day();
+        let mut season = calendar::get_season(day).get_name().to_owned();
+        if let Some(holiday) = calendar::get_holiday(day) {
+            season.push_str(" - ");
+            season.push_str(holiday.get_name());
+        }
+
+        // Employees left in the office at night do overtime
+        if game.is_night() {
+            season += if game.get_office().get_door_state().lets_through() {
+                " - Nuit"
+            } else {
+                " - Heures sup'"
+            };
+        }
+
+        for (i, line) in [calendar::describe_day(day), season].iter().enumerate() {
+            draw_text_ex(
+                line,
+                120.,
+                (i as f32).mul_add(40., 45.),
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: FONT_SIZE_DAY as u16,
+                    color: if game.is_night() { DARKBLUE } else { BLACK },
+                    ..Default::default()
+                },
+            );
+        }
         draw_text_ex(
-            &format!(
-                "Employees : {}",
-                game.get_office().employees_count().to_string()
-            ),
+            format!("Employees : {}", game.get_company().employees_count()),
             700.,
             50.,
             TextParams {
@@ -1246,7 +2724,7 @@ impl Drawing {
         );
 
         draw_text_ex(
-            &format!("Money : {}", game.get_office().get_money().round()),
+            format!("Money : {}", game.get_company().get_money().round()),
             700.,
             100.,
             TextParams {
@@ -1257,12 +2735,14 @@ impl Drawing {
             },
         );
 
+        let door_broken = matches!(game.get_office().get_door_state(), DoorState::Broken);
+
         draw_rectangle(
             self.button_global_door.x,
             self.button_global_door.y,
             self.button_global_door.w,
             self.button_global_door.h,
-            GREEN,
+            if door_broken { ORANGE } else { GREEN },
         );
         draw_texture_ex(
             &assets::ICON_DOOR,
@@ -1283,6 +2763,15 @@ impl Drawing {
             BLACK,
         );
 
+        // Door durability
+        draw_rectangle(
+            self.button_global_door.x,
+            self.button_global_door.y + self.button_global_door.h + 10.,
+            self.button_global_door.w * game.get_office().get_door_durability(),
+            15.,
+            if door_broken { RED } else { DARKGREEN },
+        );
+
         if game.get_start_door_cd() != 0. {
             let value = self.button_global_door.h as f64
                 - (get_time() - game.get_start_door_cd()) / DOOR_CD
@@ -1323,9 +2812,12 @@ impl Drawing {
             BLACK,
         );
         if game.get_start_meth_cd() != 0. {
-            let value = self.button_global_meth.h as f64
-                - (get_time() - game.get_start_meth_cd()) / METH_CD
-                    * self.button_global_meth.h as f64;
+            let value = ((get_time() - game.get_start_meth_cd())
+                / game.get_office().get_rules().meth_cooldown)
+                .mul_add(
+                    -f64::from(self.button_global_meth.h),
+                    f64::from(self.button_global_meth.h),
+                );
 
             draw_rectangle(
                 self.button_global_meth.x,
@@ -1362,8 +2854,12 @@ impl Drawing {
             BLACK,
         );
         if game.get_start_rh_cd() != 0. {
-            let value = self.button_global_rh.h as f64
-                - (get_time() - game.get_start_rh_cd()) / RH_CD * self.button_global_rh.h as f64;
+            let value = ((get_time() - game.get_start_rh_cd())
+                / game.get_office().get_rules().rh_cooldown)
+                .mul_add(
+                    -f64::from(self.button_global_rh.h),
+                    f64::from(self.button_global_rh.h),
+                );
 
             draw_rectangle(
                 self.button_global_rh.x,
@@ -1373,69 +2869,636 @@ impl Drawing {
                 LIGHTGRAY_ALPHA,
             );
         }
+
+        Self::draw_button(
+            self.button_global_memorial,
+            if self.show_memorial { RED } else { GREEN },
+            &assets::X_TEXTURE,
+        );
+        draw_rectangle_lines(
+            self.button_global_memorial.x,
+            self.button_global_memorial.y,
+            self.button_global_memorial.w,
+            self.button_global_memorial.h,
+            10.,
+            BLACK,
+        );
+
+        let window_state = game.get_office().get_window_state();
+        Self::draw_text_button(
+            self.button_global_window,
+            match window_state {
+                WindowState::Unlocked => GREEN,
+                WindowState::Locked => ORANGE,
+                WindowState::Barricaded => RED,
+            },
+            window_state.get_name(),
+            40,
+        );
+
+        Self::draw_text_button(
+            self.button_global_policies,
+            if self.show_policies { RED } else { GREEN },
+            "Règles",
+            40,
+        );
+
+        for (tool, rect) in self.tool_buttons() {
+            if !game.is_tool_allowed(tool) {
+                draw_rectangle(rect.x, rect.y, rect.w, rect.h, LOCKED_TOOL_COLOR);
+            }
+        }
     }
 
-    pub fn draw_game(&self, game: &Game) {
-        set_camera(&self.main_camera);
-        clear_background(LIGHTGRAY);
+    /// Buttons of the global stats that a campaign level can take away
+    const fn tool_buttons(&self) -> [(Tool, Rect); 4] {
+        [
+            (Tool::Meth, self.button_global_meth),
+            (Tool::Rh, self.button_global_rh),
+            (Tool::Window, self.button_global_window),
+            (Tool::Policies, self.button_global_policies),
+        ]
+    }
 
+    /// Draws a render target stretched over a rect of the screen
+    fn draw_panel(texture: &Texture2D, rect: Rect, color: Color) {
         draw_texture_ex(
-            &self.render_target_global_stat.texture,
-            GAME_WINDOW_WIDTH as f32 * 0.3,
-            GAME_WINDOW_HEIGHT as f32 * 0.01,
-            WHITE,
+            texture,
+            rect.x,
+            rect.y,
+            color,
             DrawTextureParams {
-                dest_size: Some(vec2(
-                    GAME_WINDOW_WIDTH as f32 * 0.69,
-                    GAME_WINDOW_HEIGHT as f32 * 0.28,
-                )),
+                dest_size: Some(rect.size()),
                 flip_y: true,
                 ..Default::default()
             },
         );
+    }
 
-        draw_texture_ex(
+    /// Draws every panel where the layout of the window puts it
+    pub fn draw_game(&self, game: &Game) {
+        let layout = ScreenLayout::current();
+
+        set_default_camera();
+        clear_background(LIGHTGRAY);
+
+        Self::draw_panel(
+            &self.render_target_global_stat.texture,
+            layout.global_stat,
+            WHITE,
+        );
+        Self::draw_panel(
             &self.render_target_personnal_stat.texture,
-            GAME_WINDOW_WIDTH as f32 * 0.01,
-            GAME_WINDOW_HEIGHT as f32 * 0.01,
+            layout.personnal_stat,
             WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(
-                    GAME_WINDOW_WIDTH as f32 * 0.28,
-                    GAME_WINDOW_HEIGHT as f32 * 0.28,
-                )),
-                flip_y: true,
+        );
+        Self::draw_panel(&self.render_target_info.texture, layout.info, WHITE);
+
+        // The light of the season, dimmed at night
+        let night_value = game.get_night_value();
+        let light = match calendar::get_season(game.get_office().get_day()) {
+            Season::Spring => WHITE,
+            Season::Summer => Color::new(1., 0.97, 0.9, 1.),
+            Season::Autumn => Color::new(1., 0.94, 0.88, 1.),
+            Season::Winter => Color::new(0.9, 0.94, 1., 1.),
+        };
+        Self::draw_panel(
+            &self.render_target_office.texture,
+            layout.office,
+            Color::new(
+                light.r * night_value,
+                light.g * night_value,
+                light.b * night_value,
+                1.,
+            ),
+        );
+    }
+
+    /// Draws the main render target, where the menus are, centered on the screen
+    fn draw_main_target(&self) {
+        set_default_camera();
+        clear_background(BLACK);
+
+        Self::draw_panel(
+            &self.main_render_target.texture,
+            ScreenLayout::current().menu,
+            WHITE,
+        );
+    }
+
+    /// Starts drawing a panel over the menu, in the main render target
+    fn begin_overlay(&self) {
+        set_camera(&self.main_camera);
+        clear_background(Color::new(0., 0., 0., 0.));
+
+        draw_rectangle(
+            30.,
+            30.,
+            GAME_WINDOW_WIDTH as f32 - 60.,
+            GAME_WINDOW_HEIGHT as f32 - 60.,
+            Color::new(1., 1., 1., 0.85),
+        );
+    }
+
+    fn end_overlay(&self) {
+        set_default_camera();
+        Self::draw_panel(
+            &self.main_render_target.texture,
+            ScreenLayout::current().menu,
+            WHITE,
+        );
+    }
+
+    fn overlay_text(text: &str, x: f32, y: f32, size: u16, color: Color) {
+        draw_text_ex(
+            text,
+            x,
+            y,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: size,
+                color,
                 ..Default::default()
             },
         );
+    }
 
-        draw_texture_ex(
-            &self.render_target_info.texture,
-            GAME_WINDOW_WIDTH as f32 * 0.01,
-            GAME_WINDOW_HEIGHT as f32 * 0.3,
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(
-                    GAME_WINDOW_WIDTH as f32 * 0.28,
-                    GAME_WINDOW_HEIGHT as f32 * 0.69,
-                )),
-                flip_y: true,
+    /// Stats, score and graphs of the run, over the clouds of the game over.
+    /// `rank` is the place of the run in the high scores, if it got in, and
+    /// `level` the campaign level that was played.
+    pub fn draw_run_summary(
+        &self,
+        summary: &RunSummary,
+        rank: Option<usize>,
+        level: Option<&LevelRun>,
+    ) {
+        self.begin_overlay();
+
+        let text = |text: &str, x: f32, y: f32, size: u16| {
+            Self::overlay_text(text, x, y, size, BLACK);
+        };
+
+        if let Some(level) = level {
+            text(
+                if level.is_won() {
+                    "Niveau réussi !"
+                } else {
+                    "Niveau échoué"
+                },
+                60.,
+                100.,
+                70,
+            );
+            text(level.get_level().name, 60., 145., 30);
+        } else {
+            text("Vous avez perdu !", 60., 100., 70);
+            text("Vous n'aviez plus d'employés à exploiter !", 60., 145., 30);
+        }
+        Self::overlay_text("Cliquez pour revenir au menu", 940., 690., 24, DARKGRAY);
+
+        let lines = [
+            format!("Jours survécus : {}", summary.days),
+            format!("Argent gagné : {}", summary.earned.round()),
+            format!("Effectif maximum : {}", summary.peak_headcount),
+            format!("Employés embauchés : {}", summary.hired),
+            format!("Morts : {}", summary.died),
+            format!("Enfuis : {}", summary.fled),
+            format!("Défenestrés : {}", summary.jumped),
+            format!("Brûlés : {}", summary.burned),
+            format!(
+                "Décisions : {} oui, {} non",
+                summary.qte_accepted, summary.qte_refused
+            ),
+            format!("Méth : {} fois", summary.meth_count),
+            format!("RH : {} fois", summary.rh_count),
+        ];
+        for (i, line) in lines.iter().enumerate() {
+            text(line, 60., (i as f32).mul_add(38., 205.), 28);
+        }
+
+        if let Some(level) = level {
+            Self::draw_stars(vec2(80., 645.), level.get_level(), level.get_stars(), 22.);
+        } else {
+            let score = if (summary.score_factor - 1.).abs() < f32::EPSILON {
+                format!("Score : {}", summary.get_score())
+            } else {
+                format!(
+                    "Score : {} (x{:.1})",
+                    summary.get_score(),
+                    summary.score_factor
+                )
+            };
+            text(&score, 60., 660., 50);
+        }
+        if let Some(rank) = rank {
+            Self::overlay_text(
+                &format!("Nouveau record ! {}e place", rank + 1),
+                360.,
+                655.,
+                30,
+                DARKGREEN,
+            );
+        }
+
+        Self::draw_graph(
+            Rect::new(640., 200., 580., 190.),
+            "Argent",
+            &summary.money_history,
+            DARKGREEN,
+        );
+        Self::draw_graph(
+            Rect::new(640., 470., 580., 190.),
+            "Employés",
+            &summary.headcount_history,
+            DARKBLUE,
+        );
+
+        self.end_overlay();
+    }
+
+    fn draw_star(center: Vec2, radius: f32, color: Color) {
+        let point = |i: usize| {
+            let r = if i.is_multiple_of(2) {
+                radius
+            } else {
+                radius * 0.45
+            };
+            center + Vec2::from_angle(-PI / 2. + i as f32 * PI / 5.) * r
+        };
+
+        for i in 0..10 {
+            draw_triangle(center, point(i), point(i + 1), color);
+        }
+    }
+
+    /// A star for each objective of the level, the won ones in gold
+    fn draw_stars(pos: Vec2, level: &Level, stars: usize, radius: f32) {
+        for i in 0..level.objectives.len() {
+            let center = pos + vec2(i as f32 * radius * 2.4, 0.);
+            Self::draw_star(center, radius, if i < stars { GOLD } else { LIGHTGRAY });
+        }
+    }
+
+    /// Row of the level in the level select, in the main render target
+    pub const fn level_row_rect(index: usize) -> Rect {
+        Rect::new(60., (index as f32).mul_add(105., 130.), 1160., 90.)
+    }
+
+    /// The levels of the campaign, their stars and the locked ones
+    pub fn draw_level_select(&self, campaign: &Campaign) {
+        self.begin_overlay();
+
+        Self::overlay_text("Campagne", 60., 95., 60, BLACK);
+        Self::overlay_text(
+            "Cliquez sur un niveau ou tapez son numéro - Échap : retour",
+            60.,
+            680.,
+            24,
+            DARKGRAY,
+        );
+
+        for (i, level) in LEVELS.iter().enumerate() {
+            let rect = Self::level_row_rect(i);
+            let unlocked = campaign.is_unlocked(i);
+
+            draw_rectangle(
+                rect.x,
+                rect.y,
+                rect.w,
+                rect.h,
+                if unlocked { WHITE } else { LIGHTGRAY },
+            );
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2., DARKGRAY);
+            Self::overlay_text(
+                &format!("{}. {}", i + 1, level.name),
+                rect.x + 20.,
+                rect.y + 40.,
+                34,
+                if unlocked { BLACK } else { GRAY },
+            );
+
+            let details = if unlocked {
+                format!(
+                    "{} - Outils : {}",
+                    level.objectives[0].get_description(),
+                    level
+                        .tools
+                        .iter()
+                        .map(|t| t.get_name())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )
+            } else {
+                "Réussissez le niveau précédent pour le débloquer".to_owned()
+            };
+            Self::overlay_text(&details, rect.x + 20., rect.y + 72., 22, DARKGRAY);
+
+            Self::draw_stars(
+                vec2(rect.right() - 150., rect.center().y),
+                level,
+                campaign.get_stars(i),
+                20.,
+            );
+        }
+
+        self.end_overlay();
+    }
+
+    /// What the manager expects from the level, before its intro
+    pub fn draw_briefing(&self, level: &Level) {
+        self.begin_overlay();
+
+        Self::overlay_text(level.name, 60., 100., 60, BLACK);
+
+        let lines = Self::wrap_text(level.briefing, 30, 1140.);
+        for (i, line) in lines.iter().enumerate() {
+            Self::overlay_text(line, 60., (i as f32).mul_add(40., 160.), 30, DARKGRAY);
+        }
+
+        let y = (lines.len() as f32).mul_add(40., 200.);
+        Self::overlay_text("Objectifs", 60., y, 36, BLACK);
+        for (i, objective) in level.objectives.iter().enumerate() {
+            let text = if i == 0 {
+                format!("{} (pour réussir le niveau)", objective.get_description())
+            } else {
+                objective.get_description()
+            };
+            Self::draw_star(vec2(75., (i as f32).mul_add(40., y + 37.)), 12., GOLD);
+            Self::overlay_text(&text, 100., (i as f32).mul_add(40., y + 45.), 28, BLACK);
+        }
+
+        let tools = Tool::ALL
+            .into_iter()
+            .map(|tool| {
+                if level.allows_tool(tool) {
+                    tool.get_name().to_owned()
+                } else {
+                    format!("{} (verrouillé)", tool.get_name())
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        Self::overlay_text(&format!("Outils : {tools}"), 60., y + 190., 26, DARKGRAY);
+        Self::overlay_text(
+            &format!(
+                "{} employés et {} en caisse au départ",
+                level.staff, level.money
+            ),
+            60.,
+            y + 230.,
+            26,
+            DARKGRAY,
+        );
+
+        Self::overlay_text(
+            "Cliquez pour commencer - Échap : retour",
+            60.,
+            680.,
+            24,
+            DARKGRAY,
+        );
+
+        self.end_overlay();
+    }
+
+    /// Objectives of the campaign level, in the corner of the office
+    fn draw_level_objectives(game: &Game) {
+        let Some(run) = game.get_level() else {
+            return;
+        };
+        let level = run.get_level();
+
+        let lines = std::iter::once((
+            format!("Niveau {} : {}", run.get_index() + 1, level.name),
+            BLACK,
+        ))
+        .chain(
+            level.objectives.iter().zip(run.get_statuses()).map(
+                |(objective, status)| match status {
+                    ObjectiveStatus::Pending => {
+                        (format!("[ ] {}", objective.get_description()), BLACK)
+                    }
+                    ObjectiveStatus::Done => {
+                        (format!("[x] {}", objective.get_description()), DARKGREEN)
+                    }
+                    ObjectiveStatus::Failed => {
+                        (format!("[-] {}", objective.get_description()), RED)
+                    }
+                },
+            ),
+        )
+        .collect::<Vec<(String, Color)>>();
+
+        Self::draw_corner_panel(&lines);
+    }
+
+    /// Milestone and difficulty of the endless run, in the corner of the office
+    fn draw_endless_milestones(game: &Game) {
+        let day = game.get_office().get_day();
+        let Some(milestone) = game.get_mode().get_milestone(day) else {
+            return;
+        };
+        if game.get_level().is_some() {
+            return;
+        }
+
+        let highlighted = game
+            .get_milestone_time()
+            .is_some_and(|time| get_time() - time < MILESTONE_HIGHLIGHT_TIME);
+
+        let lines = [
+            if highlighted {
+                (format!("Palier {milestone} atteint !"), DARKGREEN)
+            } else {
+                (format!("Crunch sans fin - palier {milestone}"), BLACK)
+            },
+            (format!("Difficulté x{:.2}", game.get_escalation()), BLACK),
+            (
+                format!(
+                    "Prochain palier : jour {}",
+                    (milestone + 1) * ENDLESS_MILESTONE_DAYS + 1
+                ),
+                DARKGRAY,
+            ),
+            (
+                format!("Score x{:.1}", game.get_mode().get_score_factor(day)),
+                DARKGRAY,
+            ),
+        ];
+
+        Self::draw_corner_panel(&lines);
+    }
+
+    /// Lines in a frame at the bottom left of the office
+    fn draw_corner_panel(lines: &[(String, Color)]) {
+        let line_height = f32::from(TOOLTIP_FONT_SIZE) * 1.3;
+        let width = TOOLTIP_PADDING.mul_add(
+            2.,
+            lines
+                .iter()
+                .map(|(text, _)| {
+                    measure_text(text, Some(&assets::FONT), TOOLTIP_FONT_SIZE, 1.).width
+                })
+                .fold(0., f32::max),
+        );
+        let height = line_height * lines.len() as f32 + TOOLTIP_PADDING * 2.;
+        let office = ScreenLayout::current().office;
+        let pos = vec2(
+            office.x + TOOLTIP_PADDING,
+            office.bottom() - height - TOOLTIP_PADDING,
+        );
+
+        draw_rectangle(pos.x, pos.y, width, height, Color::new(1., 1., 1., 0.85));
+        draw_rectangle_lines(pos.x, pos.y, width, height, 2., DARKGRAY);
+        for (i, (text, color)) in lines.iter().enumerate() {
+            draw_text_ex(
+                text,
+                pos.x + TOOLTIP_PADDING,
+                pos.y + TOOLTIP_PADDING + line_height * (i as f32 + 0.8),
+                Self::tooltip_params(*color),
+            );
+        }
+    }
+
+    /// Every achievement, and whether it was unlocked
+    pub fn draw_achievements(&self, achievements: &Achievements) {
+        self.begin_overlay();
+
+        Self::overlay_text(
+            &format!(
+                "Succès ({}/{})",
+                achievements.unlocked_count(),
+                Achievement::ALL.len()
+            ),
+            60.,
+            95.,
+            60,
+            BLACK,
+        );
+        Self::overlay_text("Cliquez pour revenir", 60., 680., 24, DARKGRAY);
+
+        for (i, achievement) in Achievement::ALL.into_iter().enumerate() {
+            let y = (i as f32).mul_add(95., 170.);
+            let unlocked = achievements.is_unlocked(achievement);
+
+            draw_rectangle(
+                60.,
+                y - 35.,
+                40.,
+                40.,
+                if unlocked { GOLD } else { LIGHTGRAY },
+            );
+            Self::overlay_text(
+                achievement.get_name(),
+                120.,
+                y - 8.,
+                34,
+                if unlocked { BLACK } else { GRAY },
+            );
+            Self::overlay_text(&achievement.get_description(), 120., y + 25., 24, DARKGRAY);
+        }
+
+        self.end_overlay();
+    }
+
+    /// The best runs of each game mode, side by side
+    pub fn draw_high_scores(&self, scores: &HighScores) {
+        self.begin_overlay();
+
+        Self::overlay_text("Meilleurs scores", 60., 95., 60, BLACK);
+        Self::overlay_text("Cliquez pour revenir", 60., 680., 24, DARKGRAY);
+
+        let column_width = 1160. / GameMode::ALL.len() as f32;
+
+        for (column, mode) in GameMode::ALL.into_iter().enumerate() {
+            let x = (column as f32).mul_add(column_width, 60.);
+            Self::overlay_text(mode.get_name(), x, 150., 36, BLACK);
+
+            let mut empty = true;
+            for (i, run) in scores.get_best(mode).enumerate() {
+                let y = (i as f32).mul_add(48., 190.);
+                empty = false;
+
+                Self::overlay_text(
+                    &format!(
+                        "{}. {} pts - {} - {}",
+                        i + 1,
+                        run.score,
+                        run.rules.get_name(),
+                        run.date
+                    ),
+                    x,
+                    y,
+                    20,
+                    BLACK,
+                );
+                Self::overlay_text(
+                    &format!(
+                        "{} j, {} gagnés, {} max, {} pertes, graine {}",
+                        run.days,
+                        run.earned.round(),
+                        run.peak_headcount,
+                        run.lost,
+                        run.seed
+                    ),
+                    x + 25.,
+                    y + 20.,
+                    13,
+                    DARKGRAY,
+                );
+            }
+
+            if empty {
+                Self::overlay_text("Aucune partie terminée", x, 190., 22, DARKGRAY);
+            }
+        }
+
+        self.end_overlay();
+    }
+
+    /// Values from the first to the last day, scaled to fit in the rect
+    fn draw_graph(rect: Rect, title: &str, values: &[f32], color: Color) {
+        draw_text_ex(
+            title,
+            rect.x,
+            rect.y - 12.,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: 28,
+                color: BLACK,
                 ..Default::default()
             },
         );
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2., DARKGRAY);
+
+        let min = values.iter().copied().fold(0., f32::min);
+        let max = values.iter().copied().fold(min + 1., f32::max);
+        let point = |i: usize, value: f32| {
+            vec2(
+                rect.x + rect.w * i as f32 / (values.len() - 1).max(1) as f32,
+                ((value - min) / (max - min)).mul_add(-rect.h, rect.bottom()),
+            )
+        };
+
+        // The zero line when the values go below it
+        if min < 0. {
+            let zero = point(0, 0.).y;
+            draw_line(rect.x, zero, rect.right(), zero, 1., LIGHTGRAY);
+        }
 
-        let night_value = game.get_night_value();
-        draw_texture_ex(
-            &self.render_target_office.texture,
-            GAME_WINDOW_WIDTH as f32 * 0.3,
-            GAME_WINDOW_HEIGHT as f32 * 0.3,
-            Color::new(night_value, night_value, night_value, night_value),
-            DrawTextureParams {
-                dest_size: Some(vec2(
-                    GAME_WINDOW_WIDTH as f32 * 0.69,
-                    GAME_WINDOW_HEIGHT as f32 * 0.69,
-                )),
-                flip_y: true,
+        for (i, pair) in values.windows(2).enumerate() {
+            let (a, b) = (point(i, pair[0]), point(i + 1, pair[1]));
+            draw_line(a.x, a.y, b.x, b.y, 3., color);
+        }
+
+        draw_text_ex(
+            format!("{}", max.round()),
+            rect.right() + 5.,
+            rect.y + 15.,
+            TextParams {
+                font: Some(&assets::FONT),
+                font_size: 18,
+                color: DARKGRAY,
                 ..Default::default()
             },
         );
@@ -1462,106 +3525,173 @@ impl Drawing {
         self.draw_office(game);
         self.draw_info(game);
         self.draw_game(game);
+        Self::draw_level_objectives(game);
+        Self::draw_endless_milestones(game);
+        self.draw_tooltips(game);
+        Self::draw_achievement_toast(game);
+    }
 
-        set_default_camera();
-        clear_background(BLACK);
+    pub fn draw_layout_editor(&self, editor: &LayoutEditor) {
+        set_camera(&self.main_camera);
+        clear_background(WHITE);
 
-        let height = screen_width() / 16. * 9.;
+        let layout = editor.get_layout();
+        Self::draw_layout(layout);
 
-        draw_texture_ex(
-            &self.main_render_target.texture,
-            0.,
-            (screen_height() - height) / 2.,
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(screen_width(), height)),
-                flip_y: true,
-                ..Default::default()
-            },
-        );
-    }
+        let lines = |from: f32, to: f32| {
+            std::iter::successors(Some(from), |x| Some(x + GRID_SIZE * 2.))
+                .take_while(move |&x| x <= to)
+        };
+        for x in lines(ROOM.x, ROOM.right()) {
+            draw_line(x, ROOM.y, x, ROOM.bottom(), 1., LIGHTGRAY_ALPHA);
+        }
+        for y in lines(ROOM.y, ROOM.bottom()) {
+            draw_line(ROOM.x, y, ROOM.right(), y, 1., LIGHTGRAY_ALPHA);
+        }
 
-    pub fn draw_menu(&mut self, game: &Game) {
-        self.draw_office_full(game);
-        self.draw_game_menu();
+        for zone in &layout.zones {
+            let rect = zone.get_rect();
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, zone.kind.get_color());
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 3., DARKGRAY);
+            draw_text_ex(
+                zone.kind.get_name(),
+                rect.x + 5.,
+                rect.y + 18.,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 16,
+                    color: BLACK,
+                    ..Default::default()
+                },
+            );
+            for slot in zone.slots() {
+                draw_circle_lines(slot.x, slot.y, 12., 2., DARKGRAY);
+            }
+        }
 
-        set_default_camera();
-        clear_background(BLACK);
+        for desk in &layout.desks {
+            for seat in 0..4 {
+                let pos = desk.seat(seat);
+
+                if desk.computers[seat] {
+                    let (computer, rotation) = desk.computer(seat);
+                    draw_texture_ex(
+                        &assets::COMPUTER_TEXTURE,
+                        computer.x - 25.,
+                        computer.y - 25.,
+                        WHITE,
+                        DrawTextureParams {
+                            rotation,
+                            dest_size: Some(Vec2::new(50., 50.)),
+                            ..Default::default()
+                        },
+                    );
+                    draw_circle(pos.x, pos.y, 15., Color::new(0., 0.89, 0.19, 0.6));
+                } else {
+                    draw_circle_lines(pos.x, pos.y, 15., 2., GRAY);
+                }
+            }
+        }
 
-        let height = screen_width() / 16. * 9.;
+        draw_rectangle(0., 0., 1280., 75., Color::new(1., 1., 1., 0.85));
 
-        draw_texture_ex(
-            &self.main_render_target.texture,
-            0.,
-            0.,
-            WHITE,
-            DrawTextureParams {
-                dest_size: Some(vec2(screen_width(), screen_height())),
-                flip_y: true,
-                ..Default::default()
-            },
+        let text = |text: &str, x: f32, y: f32, color: Color| {
+            draw_text_ex(
+                text,
+                x,
+                y,
+                TextParams {
+                    font: Some(&assets::FONT),
+                    font_size: 20,
+                    color,
+                    ..Default::default()
+                },
+            );
+        };
+
+        text(
+            &format!(
+                "Outil : {}   (1 bureau, 2 cuisine, 3 pause, 4 dortoir, 5 téléphone)",
+                editor.get_tool().get_name()
+            ),
+            20.,
+            25.,
+            BLACK,
+        );
+        text(
+            "Clic : placer, déplacer, (dés)activer une place   Clic droit : retirer   R : plan d'origine   S : enregistrer   Échap : retour",
+            20.,
+            48.,
+            DARKGRAY,
         );
-    }
 
-    pub fn convert_screen_main(coords: Vec2) -> Vec2 {
-        let height = screen_width() / 16. * 9.;
-        let y = (screen_height() - height) / 2.;
+        if let Some(message) = editor.get_message() {
+            text(message, 20., 70., BLUE);
+        } else if let Some(error) = editor.get_error() {
+            text(error, 20., 70., RED);
+        } else {
+            text("Plan valide.", 20., 70., DARKGREEN);
+        }
 
-        vec2(
-            ((coords.x - 0.) / screen_width()) * GAME_WINDOW_WIDTH as f32,
-            ((coords.y - y) / height) * GAME_WINDOW_HEIGHT as f32,
-        )
+        self.draw_main_target();
     }
 
-    pub fn convert_main_office(main_coords: Vec2) -> Vec2 {
-        let x = GAME_WINDOW_WIDTH as f32 * 0.3;
-        let width = GAME_WINDOW_WIDTH as f32 * 0.69;
-
-        let y = GAME_WINDOW_HEIGHT as f32 * 0.3;
-        let height = GAME_WINDOW_HEIGHT as f32 * 0.69;
+    pub fn draw_menu(&mut self, game: &Game) {
+        self.draw_office_full(game);
+        self.draw_game_menu();
+        self.draw_main_target();
+    }
 
-        vec2(
-            ((main_coords.x - x) / width) * OFFICE_WIDTH as f32,
-            ((main_coords.y - y) / height) * OFFICE_HEIGHT as f32,
+    /// Position in the main render target, where the menus are
+    pub fn convert_screen_menu(coords: Vec2) -> Vec2 {
+        to_panel(
+            ScreenLayout::current().menu,
+            vec2(GAME_WINDOW_WIDTH as f32, GAME_WINDOW_HEIGHT as f32),
+            coords,
         )
     }
 
-    pub fn convert_main_info(main_coords: Vec2) -> Vec2 {
-        let x = GAME_WINDOW_WIDTH as f32 * 0.01;
-        let width = GAME_WINDOW_WIDTH as f32 * 0.28;
-
-        let y = GAME_WINDOW_HEIGHT as f32 * 0.3;
-        let height = GAME_WINDOW_HEIGHT as f32 * 0.69;
-
-        vec2(
-            ((main_coords.x - x) / width) * INFO_WIDTH as f32,
-            ((main_coords.y - y) / height) * INFO_HEIGHT as f32,
-        )
+    /// Position in the office itself, through the zoom of the camera
+    pub fn convert_screen_office(&self, coords: Vec2) -> Vec2 {
+        self.convert_office_view_world(Self::convert_screen_office_view(coords))
     }
 
-    pub fn convert_main_personnal_stat(main_coords: Vec2) -> Vec2 {
-        let x = GAME_WINDOW_WIDTH as f32 * 0.01;
-        let width = GAME_WINDOW_WIDTH as f32 * 0.28;
+    fn convert_office_view_world(&self, view_coords: Vec2) -> Vec2 {
+        let view = self.office_view_rect();
 
-        let y = GAME_WINDOW_HEIGHT as f32 * 0.01;
-        let height = GAME_WINDOW_HEIGHT as f32 * 0.28;
+        view.point() + view_coords / self.office_zoom
+    }
 
-        vec2(
-            ((main_coords.x - x) / width) * PERSONNAL_STAT_WIDTH as f32,
-            ((main_coords.y - y) / height) * PERSONNAL_STAT_HEIGHT as f32,
+    /// Position in the office view, where the tabs and buttons are
+    pub fn convert_screen_office_view(coords: Vec2) -> Vec2 {
+        to_panel(
+            ScreenLayout::current().office,
+            vec2(OFFICE_WIDTH as f32, OFFICE_HEIGHT as f32),
+            coords,
         )
     }
 
-    pub fn convert_main_global_stat(main_coords: Vec2) -> Vec2 {
-        let x = GAME_WINDOW_WIDTH as f32 * 0.3;
-        let width = GAME_WINDOW_WIDTH as f32 * 0.69;
+    pub fn convert_screen_info(coords: Vec2) -> Vec2 {
+        to_panel(
+            ScreenLayout::current().info,
+            vec2(INFO_WIDTH as f32, INFO_HEIGHT as f32),
+            coords,
+        )
+    }
 
-        let y = GAME_WINDOW_HEIGHT as f32 * 0.01;
-        let height = GAME_WINDOW_HEIGHT as f32 * 0.28;
+    pub fn convert_screen_personnal_stat(coords: Vec2) -> Vec2 {
+        to_panel(
+            ScreenLayout::current().personnal_stat,
+            vec2(PERSONNAL_STAT_WIDTH as f32, PERSONNAL_STAT_HEIGHT as f32),
+            coords,
+        )
+    }
 
-        vec2(
-            ((main_coords.x - x) / width) * GLOBAL_STAT_WIDTH as f32,
-            ((main_coords.y - y) / height) * GLOBAL_STAT_HEIGHT as f32,
+    pub fn convert_screen_global_stat(coords: Vec2) -> Vec2 {
+        to_panel(
+            ScreenLayout::current().global_stat,
+            vec2(GLOBAL_STAT_WIDTH as f32, GLOBAL_STAT_HEIGHT as f32),
+            coords,
         )
     }
 
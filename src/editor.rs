@@ -0,0 +1,203 @@
+use macroquad::prelude::*;
+
+use crate::{
+    drawing::Drawing,
+    layout::{snap, DeskPlacement, Layout, ZonePlacement, DESK_SIZE, ROOM},
+    zone::ZoneKind,
+};
+
+/// Distance under which a click toggles the computer of a seat
+const SEAT_RADIUS: f32 = 20.;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EditorTool {
+    Desk,
+    Zone(ZoneKind),
+}
+
+impl EditorTool {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Desk => "Bureau",
+            Self::Zone(kind) => kind.get_name(),
+        }
+    }
+}
+
+enum Dragging {
+    Desk(usize, Vec2),
+    Zone(usize, Vec2),
+}
+
+pub enum EditorEvent {
+    None,
+    /// The layout was saved and should be used by the office
+    Saved,
+    Quit,
+}
+
+/// Places the desks, computers and zones of the office on a grid
+pub struct LayoutEditor {
+    layout: Layout,
+    tool: EditorTool,
+    dragging: Option<Dragging>,
+    /// Why the layout can not be saved, if it can not
+    error: Option<String>,
+    message: Option<String>,
+}
+
+impl LayoutEditor {
+    pub fn new(layout: Layout) -> Self {
+        let error = layout.validate().err();
+
+        Self {
+            layout,
+            tool: EditorTool::Desk,
+            dragging: None,
+            error,
+            message: None,
+        }
+    }
+
+    fn changed(&mut self) {
+        self.error = self.layout.validate().err();
+        self.message = None;
+    }
+
+    pub fn tick(&mut self) -> EditorEvent {
+        let mouse = Drawing::convert_screen_menu(vec2(mouse_position().0, mouse_position().1));
+
+        let tools = [
+            (KeyCode::Key1, EditorTool::Desk),
+            (KeyCode::Key2, EditorTool::Zone(ZoneKind::Kitchen)),
+            (KeyCode::Key3, EditorTool::Zone(ZoneKind::BreakRoom)),
+            (KeyCode::Key4, EditorTool::Zone(ZoneKind::SleepingPods)),
+            (KeyCode::Key5, EditorTool::Zone(ZoneKind::PhoneBooth)),
+        ];
+        for (key, tool) in tools {
+            if is_key_pressed(key) {
+                self.tool = tool;
+            }
+        }
+
+        if is_key_pressed(KeyCode::Escape) {
+            return EditorEvent::Quit;
+        }
+
+        if is_key_pressed(KeyCode::R) {
+            self.layout = Layout::default();
+            self.changed();
+        }
+
+        if is_key_pressed(KeyCode::S) {
+            return match self.layout.save() {
+                Ok(()) => {
+                    self.message = Some("Plan enregistré.".to_owned());
+                    EditorEvent::Saved
+                }
+                Err(error) => {
+                    self.message = Some(format!("Enregistrement impossible : {error}"));
+                    EditorEvent::None
+                }
+            };
+        }
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            self.click(mouse);
+        } else if is_mouse_button_down(MouseButton::Left) {
+            self.drag(mouse);
+        } else if self.dragging.take().is_some() {
+            self.changed();
+        }
+
+        if is_mouse_button_pressed(MouseButton::Right) {
+            self.remove(mouse);
+        }
+
+        EditorEvent::None
+    }
+
+    fn desk_at(&self, pos: Vec2) -> Option<usize> {
+        self.layout
+            .desks
+            .iter()
+            .position(|d| d.get_rect().contains(pos))
+    }
+
+    fn zone_at(&self, pos: Vec2) -> Option<usize> {
+        self.layout
+            .zones
+            .iter()
+            .position(|z| z.get_rect().contains(pos))
+    }
+
+    fn click(&mut self, mouse: Vec2) {
+        for desk in &mut self.layout.desks {
+            if let Some(seat) = (0..4).find(|&s| desk.seat(s).distance(mouse) < SEAT_RADIUS) {
+                desk.computers[seat] = !desk.computers[seat];
+                self.changed();
+                return;
+            }
+        }
+
+        if let Some(i) = self.desk_at(mouse) {
+            self.dragging = Some(Dragging::Desk(i, mouse - self.layout.desks[i].pos));
+        } else if let Some(i) = self.zone_at(mouse) {
+            self.dragging = Some(Dragging::Zone(i, mouse - self.layout.zones[i].pos));
+        } else if ROOM.contains(mouse) {
+            match self.tool {
+                EditorTool::Desk => {
+                    self.layout
+                        .desks
+                        .push(DeskPlacement::new(snap(mouse - DESK_SIZE / 2.)));
+                }
+                EditorTool::Zone(kind) => {
+                    // There is a single zone of each kind, placing it again moves it
+                    self.layout.zones.retain(|z| z.kind != kind);
+
+                    let mut zone = ZonePlacement {
+                        kind,
+                        pos: Vec2::ZERO,
+                    };
+                    zone.pos = snap(mouse - zone.get_rect().size() / 2.);
+                    self.layout.zones.push(zone);
+                }
+            }
+            self.changed();
+        }
+    }
+
+    fn drag(&mut self, mouse: Vec2) {
+        match self.dragging {
+            Some(Dragging::Desk(i, offset)) => self.layout.desks[i].pos = snap(mouse - offset),
+            Some(Dragging::Zone(i, offset)) => self.layout.zones[i].pos = snap(mouse - offset),
+            None => (),
+        }
+    }
+
+    fn remove(&mut self, mouse: Vec2) {
+        if let Some(i) = self.desk_at(mouse) {
+            self.layout.desks.remove(i);
+            self.changed();
+        } else if let Some(i) = self.zone_at(mouse) {
+            self.layout.zones.remove(i);
+            self.changed();
+        }
+    }
+
+    pub const fn get_layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    pub const fn get_tool(&self) -> EditorTool {
+        self.tool
+    }
+
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn get_message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
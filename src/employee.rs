@@ -1,31 +1,39 @@
 use macroquad::prelude::*;
-use macroquad_particles::{self as particles, AtlasConfig, BlendMode, Emitter, EmitterConfig};
+use macroquad_particles::{self as particles, BlendMode, Emitter, EmitterConfig};
 
 use std::{
     cell::{Ref, RefCell, RefMut},
+    collections::VecDeque,
     f32::consts::PI,
     rc::Rc,
 };
 
-const NAMES: &[&str] = &[
-    "Baptiste", "Yannis", "Valentin", "Cynthia", "Alain", "Valérie", "Yves", "Cédric", "Marcello",
-    "Edsger", "Stephano", "Olivier", "Mathieu", "Valérie", "Roland", "Tom",
-];
-
-pub const BONUS_RH_COST: f32 = 200.;
-
-use macroquad::{
-    math::Vec2,
-    rand::{self, gen_range},
+use macroquad::{math::Vec2, rand};
+
+use crate::{
+    assets,
+    drawing::OFFICE_HEIGHT,
+    fire::{
+        Fire, EXTINGUISH_COST, FIRE_DAMAGE, IGNITION_CHANCE, LETHAL_CHANCE, LETHAL_INTENSITY,
+        LETHAL_RADIUS, SPREAD_CHANCE, SPREAD_DISTANCE, SPREAD_INTENSITY,
+    },
+    history::{Fate, HistoryEntry, HistoryEvent, MemorialEntry},
+    identity::Identity,
+    layout::Layout,
+    navigation::{follow_path, NavGrid, ENTRANCE, EXIT},
+    personality::Personality,
+    policy::Policies,
+    relationship::{
+        RelationshipKind, Relationships, CONTAGION_RADIUS, CONTAGION_RATE, GRIEF_HOPE,
+        GRIEF_SATISFACTION, NEIGHBOUR_DISTANCE,
+    },
+    rules::Rules,
+    technician::Technician,
+    zone::{Zone, ZoneKind},
 };
 
-use crate::{assets, drawing::OFFICE_HEIGHT};
-
 use crate::qte::QteEffect;
 
-const SPOT_X: [f32; 4] = [450., 650., 750., 950.];
-const MIDDLE_SPOT_X: [f32; 4] = [400., 700., 700., 1000.];
-const SPOT_Y: [f32; 4] = [175., 265., 472., 551.];
 pub const MIDDLE_LANE: f32 = 350.;
 const WINDOW_X: f32 = 1090.;
 const OPEN_WINDOW_X: f32 = 1000.;
@@ -33,14 +41,42 @@ const DOOR_X_INSIDE: f32 = 370.;
 const DOOR_X_OUTSIDE: f32 = 300.;
 const SPEED_FALL: f32 = 10.;
 
-const BASE_DECAY_RATE: f32 = 0.0002;
-const REPLENISH_RATE: f32 = BASE_DECAY_RATE * 10.;
+/// Distance under which a walking employee waits for the one in front of them
+const PERSONAL_SPACE: f32 = 45.;
+/// Ticks after which a blocked employee walks through anyway
+const MAX_BLOCKED_TICKS: u32 = 60;
 
 pub const EMPLOYEE_RADIUS: f32 = 50.;
 pub const EMPLOYEE_SPEED: f32 = 2.;
 pub const EMPLOYEE_RUNNING_SPEED: f32 = 5.;
 
-pub const BONUS_METH_COST: f32 = 1000.;
+/// Energy drains this much faster when working at night, but it pays more
+const OVERTIME_ENERGY_FACTOR: f32 = 2.;
+const OVERTIME_PAY: f32 = 1.5;
+
+/// Part of the replenish rate recovered each tick while at home for the night,
+/// up to the max energy
+const HOME_REST_FACTOR: f32 = 0.5;
+const HOME_MAX_ENERGY: f32 = 0.9;
+
+/// Durability lost per tick by each employee pushing against the closed door
+const DOOR_WEAR: f32 = 0.0004;
+pub const DOOR_REPAIR_COST: f32 = 300.;
+
+pub const WINDOW_LOCK_COST: f32 = 100.;
+pub const WINDOW_BARRICADE_COST: f32 = 300.;
+const WINDOW_LOCK_UPKEEP: f32 = 20.;
+const WINDOW_BARRICADE_UPKEEP: f32 = 50.;
+/// Chance for a desperate employee to force the lock of the window
+const WINDOW_FORCE_CHANCE: f32 = 0.25;
+/// Hope left to an employee after a breakdown at the closed window
+const BREAKDOWN_HOPE: f32 = 0.2;
+
+pub const COMPUTER_RADIUS: f32 = 30.;
+
+/// Stat under which the quick filters select an employee
+const LOW_STAT: f32 = 0.3;
+pub const REPAIR_COST: f32 = 150.;
 
 #[derive(Clone, Copy, Debug)]
 pub enum DoorState {
@@ -48,28 +84,98 @@ pub enum DoorState {
     Open,
     /// The door is closed
     Closed,
-    /// The door is broken
-    _Broken,
+    /// The door is broken, it lets everyone through and can not be closed
+    Broken,
+}
+
+impl DoorState {
+    /// Whether employees can go through the door
+    pub const fn lets_through(self) -> bool {
+        matches!(self, Self::Open | Self::Broken)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowState {
+    /// Opens for anyone who wants to jump
+    Unlocked,
+    /// Closed with a lock that a desperate employee can force
+    Locked,
+    /// Nobody can go through
+    Barricaded,
+}
+
+impl WindowState {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Unlocked => "Libre",
+            Self::Locked => "Verrouillée",
+            Self::Barricaded => "Barricadée",
+        }
+    }
 }
 
 pub struct Office {
     available_computers: Vec<Rc<RefCell<Computer>>>,
     employees: Vec<Rc<RefCell<Employee>>>,
-    selected_employee: Option<Rc<RefCell<Employee>>>,
+    selected_employees: Vec<Rc<RefCell<Employee>>>,
+    selected_computer: Option<Rc<RefCell<Computer>>>,
+    technicians: Vec<Technician>,
+    fires: Vec<Rc<RefCell<Fire>>>,
+    fire_alarm: bool,
+    /// The door broke since the last call to `take_door_broken`
+    door_broken: bool,
     money: f32,
     door_state: DoorState,
+    door_durability: f32,
     window_open: bool,
+    window_state: WindowState,
+    relationships: Relationships,
+    next_employee_id: u64,
+    day: u32,
+    memorial: Vec<MemorialEntry>,
+    layout: Layout,
+    nav: NavGrid,
+    zones: Vec<Zone>,
+    policies: Policies,
+    /// From evening to dawn
+    night: bool,
+    /// Money generated by the employees since the start
+    earned: f32,
+    hired: usize,
+    rules: Rules,
 }
 
 impl Office {
     pub fn new() -> Self {
+        let layout = Layout::load();
+
         let mut new = Self {
             available_computers: Vec::new(),
             employees: Vec::new(),
-            selected_employee: None,
-            money: 2000.,
+            selected_employees: Vec::new(),
+            selected_computer: None,
+            technicians: Vec::new(),
+            fires: Vec::new(),
+            fire_alarm: false,
+            door_broken: false,
+            money: 0.,
             door_state: DoorState::Open,
+            door_durability: 1.,
             window_open: false,
+            window_state: WindowState::Unlocked,
+            relationships: Relationships::default(),
+            next_employee_id: 0,
+            day: 1,
+            memorial: Vec::new(),
+            nav: NavGrid::for_layout(&layout),
+            zones: layout.build_zones(),
+            layout,
+            policies: Policies::default(),
+            night: false,
+            earned: 0.,
+            hired: 0,
+            rules: Rules::default(),
         };
 
         new.start();
@@ -78,30 +184,156 @@ impl Office {
     }
 
     pub fn start(&mut self) {
-        let computer_diff_with_spot_x = [60., -70., 65., -80.];
-
-        self.available_computers = SPOT_X
-            .iter()
-            .enumerate()
-            .flat_map(|(i, &x)| {
-                SPOT_Y.iter().map(move |&y| {
-                    Rc::new(RefCell::new(Computer::new(
-                        Vec2::new(x + computer_diff_with_spot_x[i], y),
-                        Vec2::new(x, y),
-                        match i % 2 {
-                            0 => PI / 2.,
-                            _ => -PI / 2.,
-                        },
-                    )))
-                })
-            })
+        self.available_computers = self
+            .layout
+            .build_computers()
+            .into_iter()
+            .map(|c| Rc::new(RefCell::new(c)))
             .collect::<Vec<Rc<RefCell<Computer>>>>();
 
         self.employees.clear();
-        self.selected_employee = None;
-        self.money = 2000.;
+        self.selected_employees.clear();
+        self.selected_computer = None;
+        self.technicians.clear();
+        self.fires.clear();
+        self.fire_alarm = false;
+        self.door_broken = false;
+        self.money = self.rules.starting_money;
         self.door_state = DoorState::Open;
+        self.door_durability = 1.;
         self.window_open = false;
+        self.window_state = WindowState::Unlocked;
+        self.relationships.clear();
+        self.day = 1;
+        self.memorial.clear();
+        self.zones = self.layout.build_zones();
+        self.policies.reset();
+        self.earned = 0.;
+        self.hired = 0;
+    }
+
+    /// Rules of the run, the office starts over with their money
+    pub fn set_rules(&mut self, rules: Rules) {
+        self.rules = rules;
+        self.start();
+    }
+
+    pub const fn get_rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub const fn set_decay_factor(&mut self, factor: f32) {
+        self.rules.decay_factor = factor;
+    }
+
+    /// Replaces the furniture of the office and starts over
+    pub fn set_layout(&mut self, layout: Layout) {
+        self.nav = NavGrid::for_layout(&layout);
+        self.layout = layout;
+        self.start();
+    }
+
+    pub const fn get_layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    fn new_employee(&mut self, computer: Rc<RefCell<Computer>>) -> Employee {
+        let taken = self
+            .iter_employees()
+            .map(|e| e.get_name().to_owned())
+            .collect::<Vec<String>>();
+
+        self.next_employee_id += 1;
+
+        Employee::new(
+            self.next_employee_id,
+            computer,
+            Identity::random_unique(&taken),
+        )
+    }
+
+    fn push_employee(&mut self, mut employee: Employee) {
+        employee.record(self.day, HistoryEvent::Hired);
+        self.hired += 1;
+        self.welcome(Rc::new(RefCell::new(employee)));
+    }
+
+    /// Adds the employee to the office and lets them meet their desk neighbours
+    fn welcome(&mut self, employee: Rc<RefCell<Employee>>) {
+        {
+            let mut employee = employee.borrow_mut();
+            employee.go_to(Destination::DoorOutside, EMPLOYEE_RUNNING_SPEED, &self.nav);
+
+            let spot = employee.computer.borrow().spot;
+
+            for other in &self.employees {
+                let other = other.borrow();
+                if other.computer.borrow().spot.distance(spot) < NEIGHBOUR_DISTANCE {
+                    self.relationships.meet(employee.id, other.id);
+                }
+            }
+        }
+
+        self.employees.push(employee);
+    }
+
+    /// Frees the computer, the zone slot and the relationships of an employee
+    /// who left the office
+    fn release_employee(&mut self, employee: &Employee) {
+        self.available_computers.push(employee.computer.clone());
+        self.relationships.remove_employee(employee.id);
+        self.policies.forget(employee.id);
+        for zone in &mut self.zones {
+            zone.leave(employee.id);
+        }
+    }
+
+    pub const fn has_free_computer(&self) -> bool {
+        !self.available_computers.is_empty()
+    }
+
+    /// Removes the selected employee from the office to send them to another floor.
+    /// Only the working employees can be moved.
+    pub fn take_selected_employee(&mut self) -> Option<Rc<RefCell<Employee>>> {
+        let selected = self.get_selected_employee()?.clone();
+
+        if !matches!(selected.borrow().state, EmployeeState::Alive)
+            || selected.borrow().is_state_freezed
+        {
+            return None;
+        }
+
+        self.selected_employees.clear();
+        self.employees.retain(|e| !Rc::ptr_eq(e, &selected));
+        self.release_employee(&selected.borrow());
+
+        Some(selected)
+    }
+
+    /// Gives a free computer to an employee coming from another floor. They get
+    /// a new id, since the ids are only unique in their office.
+    pub fn receive_employee(&mut self, employee: Rc<RefCell<Employee>>) {
+        if self.available_computers.is_empty() {
+            return;
+        }
+
+        let spot_index = rand::gen_range(0, self.available_computers.len());
+        let computer = self.available_computers.remove(spot_index);
+
+        self.next_employee_id += 1;
+
+        {
+            let mut e = employee.borrow_mut();
+            e.id = self.next_employee_id;
+            e.computer = computer;
+            e.position = ENTRANCE;
+            e.state = EmployeeState::Arriving;
+            e.action = EmployeeAction::None;
+            e.using_zone = false;
+            e.record(self.day, HistoryEvent::Transferred);
+        }
+
+        self.welcome(employee);
     }
 
     pub fn add_employee(&mut self) {
@@ -109,9 +341,9 @@ impl Office {
             let spot_index = rand::gen_range(0, self.available_computers.len());
 
             let employee_spot = self.available_computers.remove(spot_index);
+            let employee = self.new_employee(employee_spot);
 
-            self.employees
-                .push(Rc::new(RefCell::new(Employee::new(employee_spot))));
+            self.push_employee(employee);
         }
     }
 
@@ -120,31 +352,304 @@ impl Office {
             let spot_index = rand::gen_range(0, self.available_computers.len());
 
             let employee_spot = self.available_computers.remove(spot_index);
-            let mut employee = Employee::new(employee_spot);
+            let mut employee = self.new_employee(employee_spot);
             employee.is_state_freezed = true;
             employee.energy = 0.5;
             employee.satisfaction = 0.5;
             employee.hope = 0.5;
             employee.satisfaction = 0.5;
 
-            self.employees.push(Rc::new(RefCell::new(employee)));
+            self.push_employee(employee);
         }
     }
 
-    pub fn get_selected_employee(&self) -> &Option<Rc<RefCell<Employee>>> {
-        &self.selected_employee
+    /// The selected employee, when there is exactly one
+    pub fn get_selected_employee(&self) -> Option<&Rc<RefCell<Employee>>> {
+        match self.selected_employees.as_slice() {
+            [selected] => Some(selected),
+            _ => None,
+        }
     }
 
-    pub fn click(&mut self, mouse_pos: Vec2) {
-        self.selected_employee = None;
+    pub fn get_selected_employees(&self) -> &[Rc<RefCell<Employee>>] {
+        &self.selected_employees
+    }
 
-        for e in &self.employees {
-            let borrowed_e = e.borrow();
+    pub fn is_selected(&self, employee: &Rc<RefCell<Employee>>) -> bool {
+        self.selected_employees
+            .iter()
+            .any(|e| Rc::ptr_eq(e, employee))
+    }
+
+    /// Selects the employees in the rect, added to the current selection if
+    /// `add` is set
+    pub fn select_in_rect(&mut self, rect: Rect, add: bool) {
+        if !add {
+            self.selected_employees.clear();
+        }
+        self.selected_computer = None;
+
+        let inside = self
+            .employees
+            .iter()
+            .filter(|e| {
+                let e = e.borrow();
+                rect.contains(e.position) && !matches!(e.state, EmployeeState::Home)
+            })
+            .filter(|e| !self.is_selected(e))
+            .cloned()
+            .collect::<Vec<Rc<RefCell<Employee>>>>();
+        self.selected_employees.extend(inside);
+    }
+
+    /// Selects every working employee matching the filter
+    pub fn select_filter(&mut self, filter: SelectionFilter) {
+        self.selected_computer = None;
+        self.selected_employees = self
+            .employees
+            .iter()
+            .filter(|e| {
+                let e = e.borrow();
+                matches!(e.state, EmployeeState::Alive) && filter.matches(&e)
+            })
+            .cloned()
+            .collect();
+    }
+
+    /// Sends every selected employee who can move to the action, or back to
+    /// work if they are all already doing it
+    pub fn toggle_selected_action(&self, action: EmployeeAction) {
+        let movable = self
+            .selected_employees
+            .iter()
+            .filter(|e| {
+                let e = e.borrow();
+                matches!(e.state, EmployeeState::Alive) && e.action != EmployeeAction::ForcedSleep
+            })
+            .collect::<Vec<&Rc<RefCell<Employee>>>>();
+
+        let next = if movable.iter().all(|e| e.borrow().action == action) {
+            EmployeeAction::None
+        } else {
+            action
+        };
+
+        for e in movable {
+            e.borrow_mut().action = next;
+        }
+    }
+
+    /// Employee drawn under the position, the one on top if several are
+    pub fn employee_at(&self, pos: Vec2) -> Option<Rc<RefCell<Employee>>> {
+        self.employees
+            .iter()
+            .rev()
+            .find(|e| {
+                let e = e.borrow();
+                e.position.distance(pos) < EMPLOYEE_RADIUS
+                    && !matches!(e.state, EmployeeState::Home)
+            })
+            .cloned()
+    }
 
-            if borrowed_e.position.distance(mouse_pos) < EMPLOYEE_RADIUS {
-                self.selected_employee = Some(e.clone());
+    /// Selects what is under the mouse. With `add`, the employee is added to
+    /// or removed from the selection instead.
+    pub fn click(&mut self, mouse_pos: Vec2, add: bool) {
+        self.selected_computer = None;
+        let clicked = self.employee_at(mouse_pos);
+
+        if add {
+            if let Some(clicked) = clicked {
+                if self.is_selected(&clicked) {
+                    self.selected_employees.retain(|e| !Rc::ptr_eq(e, &clicked));
+                } else {
+                    self.selected_employees.push(clicked);
+                }
             }
+            return;
         }
+
+        self.selected_employees = clicked.into_iter().collect();
+
+        if self.selected_employees.is_empty() {
+            let computer = self
+                .iter_computers()
+                .find(|c| c.borrow().position.distance(mouse_pos) < COMPUTER_RADIUS);
+            self.selected_computer = computer;
+        }
+    }
+
+    pub const fn get_selected_computer(&self) -> Option<&Rc<RefCell<Computer>>> {
+        self.selected_computer.as_ref()
+    }
+
+    /// Calls a technician, who takes some time to come and repair the computer
+    pub fn repair_computer(&mut self, computer: &Rc<RefCell<Computer>>) {
+        if self.is_burning(computer) {
+            return;
+        }
+
+        {
+            let mut c = computer.borrow_mut();
+            if !c.broken || c.being_repaired || self.money < REPAIR_COST {
+                return;
+            }
+            c.being_repaired = true;
+        }
+
+        self.money -= REPAIR_COST;
+        self.technicians
+            .push(Technician::new(computer.clone(), &self.nav));
+    }
+
+    /// Buys a new computer of the same tier, available right away
+    pub fn replace_computer(&mut self, computer: &Rc<RefCell<Computer>>) {
+        if self.is_burning(computer) {
+            return;
+        }
+
+        let mut c = computer.borrow_mut();
+        let cost = c.tier.get_price();
+
+        if c.broken && !c.being_repaired && self.money >= cost {
+            self.money -= cost;
+            c.broken = false;
+        }
+    }
+
+    pub fn upgrade_computer(&mut self, computer: &Rc<RefCell<Computer>>) {
+        let mut c = computer.borrow_mut();
+
+        if c.broken {
+            return;
+        }
+
+        if let Some(next) = c.tier.next() {
+            if self.money >= next.get_price() {
+                self.money -= next.get_price();
+                c.tier = next;
+            }
+        }
+    }
+
+    pub fn is_burning(&self, computer: &Rc<RefCell<Computer>>) -> bool {
+        self.fires
+            .iter()
+            .any(|f| Rc::ptr_eq(f.borrow().get_computer(), computer))
+    }
+
+    pub fn extinguish_fire(&mut self, computer: &Rc<RefCell<Computer>>) {
+        if self.is_burning(computer) && self.money >= EXTINGUISH_COST {
+            self.money -= EXTINGUISH_COST;
+            self.fires
+                .retain(|f| !Rc::ptr_eq(f.borrow().get_computer(), computer));
+        }
+    }
+
+    /// The firefighters take care of every fire
+    pub fn extinguish_all_fires(&mut self) {
+        self.fires.clear();
+    }
+
+    pub const fn is_on_fire(&self) -> bool {
+        !self.fires.is_empty()
+    }
+
+    /// Whether the fire alarm went off since the last call
+    pub const fn take_fire_alarm(&mut self) -> bool {
+        std::mem::replace(&mut self.fire_alarm, false)
+    }
+
+    pub fn iter_fires_mut(&self) -> impl Iterator<Item = RefMut<'_, Fire>> {
+        self.fires.iter().map(|f| f.borrow_mut())
+    }
+
+    /// Broken computers catch fire, fires grow, spread to the adjacent desks and
+    /// hurt the employees around them
+    fn tick_fires(&mut self) {
+        let computers = self
+            .iter_computers()
+            .collect::<Vec<Rc<RefCell<Computer>>>>();
+        let mut burning = self
+            .fires
+            .iter()
+            .map(|f| f.borrow().get_computer().clone())
+            .collect::<Vec<Rc<RefCell<Computer>>>>();
+
+        let can_ignite = |c: &Rc<RefCell<Computer>>, burning: &[Rc<RefCell<Computer>>]| {
+            !c.borrow().being_repaired && !burning.iter().any(|b| Rc::ptr_eq(b, c))
+        };
+
+        for c in &computers {
+            if c.borrow().broken
+                && can_ignite(c, &burning)
+                && rand::gen_range(0., 1.) < IGNITION_CHANCE
+            {
+                burning.push(c.clone());
+                self.fires.push(Rc::new(RefCell::new(Fire::new(c.clone()))));
+            }
+        }
+
+        let mut new_fires = Vec::new();
+
+        for fire in &self.fires {
+            let mut fire = fire.borrow_mut();
+
+            if fire.tick() {
+                self.fire_alarm = true;
+            }
+
+            let pos = fire.get_pos();
+            let intensity = fire.get_intensity();
+
+            if intensity >= SPREAD_INTENSITY && rand::gen_range(0., 1.) < SPREAD_CHANCE {
+                let neighbours = computers
+                    .iter()
+                    .filter(|c| {
+                        c.borrow().position.distance(pos) < SPREAD_DISTANCE
+                            && can_ignite(c, &burning)
+                    })
+                    .cloned()
+                    .collect::<Vec<Rc<RefCell<Computer>>>>();
+
+                if !neighbours.is_empty() {
+                    let c = neighbours[rand::gen_range(0, neighbours.len())].clone();
+                    burning.push(c.clone());
+                    new_fires.push(Rc::new(RefCell::new(Fire::new(c))));
+                }
+            }
+
+            for e in &self.employees {
+                let mut e = e.borrow_mut();
+
+                if !matches!(e.state, EmployeeState::Alive) || e.is_state_freezed {
+                    continue;
+                }
+
+                let distance = e.position.distance(pos);
+
+                if distance < fire.get_radius() {
+                    e.satisfaction = FIRE_DAMAGE
+                        .mul_add(-intensity, e.satisfaction)
+                        .clamp(0., 1.);
+                    e.hope = FIRE_DAMAGE.mul_add(-intensity, e.hope).clamp(0., 1.);
+                }
+
+                if distance < LETHAL_RADIUS
+                    && intensity >= LETHAL_INTENSITY
+                    && rand::gen_range(0., 1.) < LETHAL_CHANCE
+                {
+                    e.state = EmployeeState::Dead;
+                    e.burned = true;
+                }
+            }
+        }
+
+        self.fires.extend(new_fires);
+    }
+
+    pub fn iter_technicians(&self) -> impl Iterator<Item = &Technician> {
+        self.technicians.iter()
     }
 
     pub fn kill_random_employee(&mut self) {
@@ -184,8 +689,114 @@ impl Office {
         }
     }
 
+    pub fn repair_door(&mut self) {
+        if matches!(self.door_state, DoorState::Broken) && self.money >= DOOR_REPAIR_COST {
+            self.money -= DOOR_REPAIR_COST;
+            self.door_durability = 1.;
+            self.door_state = DoorState::Open;
+        }
+    }
+
+    /// Employees stuck behind the closed door push against it until it breaks
+    fn wear_door(&mut self) {
+        if !matches!(self.door_state, DoorState::Closed) {
+            return;
+        }
+
+        let pushers = self
+            .employees
+            .iter()
+            .filter(|e| e.borrow().is_pushing_door(self.door_state))
+            .cloned()
+            .collect::<Vec<Rc<RefCell<Employee>>>>();
+
+        self.door_durability -= DOOR_WEAR * pushers.len() as f32;
+
+        if self.door_durability <= 0. {
+            self.door_durability = 0.;
+            self.door_state = DoorState::Broken;
+            self.door_broken = true;
+
+            for e in pushers {
+                e.borrow_mut().record(self.day, HistoryEvent::BrokeDoor);
+            }
+        }
+    }
+
+    /// Whether the door broke since the last call
+    pub const fn take_door_broken(&mut self) -> bool {
+        std::mem::replace(&mut self.door_broken, false)
+    }
+
+    pub const fn get_door_durability(&self) -> f32 {
+        self.door_durability
+    }
+
+    /// Locks the window, barricades it, then frees it again
     pub fn update_window(&mut self) {
-        self.window_open = !self.window_open
+        match self.window_state {
+            WindowState::Unlocked => {
+                if self.money >= WINDOW_LOCK_COST {
+                    self.money -= WINDOW_LOCK_COST;
+                    self.window_state = WindowState::Locked;
+                }
+            }
+            WindowState::Locked => self.barricade_window(),
+            WindowState::Barricaded => self.window_state = WindowState::Unlocked,
+        }
+    }
+
+    pub fn barricade_window(&mut self) {
+        if self.window_state != WindowState::Barricaded && self.money >= WINDOW_BARRICADE_COST {
+            self.money -= WINDOW_BARRICADE_COST;
+            self.window_state = WindowState::Barricaded;
+        }
+    }
+
+    pub const fn set_window_state(&mut self, state: WindowState) {
+        self.window_state = state;
+    }
+
+    pub const fn get_window_state(&self) -> WindowState {
+        self.window_state
+    }
+
+    /// Employees who reached the window jump if they can, or break down
+    fn handle_window(&mut self) {
+        for e in &self.employees {
+            let mut e = e.borrow_mut();
+
+            if !matches!(e.state, EmployeeState::Suicide) || !e.has_arrived(Destination::Window) {
+                continue;
+            }
+
+            let forced = self.window_state == WindowState::Locked
+                && rand::gen_range(0., 1.) < WINDOW_FORCE_CHANCE;
+
+            if self.window_state == WindowState::Unlocked || forced {
+                if forced {
+                    self.window_state = WindowState::Unlocked;
+                }
+                e.state = EmployeeState::Falling;
+            } else {
+                e.state = EmployeeState::Alive;
+                e.hope = BREAKDOWN_HOPE;
+                e.satisfaction = (e.satisfaction - 0.3).max(0.);
+                e.energy = 0.;
+                e.action = EmployeeAction::ForcedSleep;
+                e.record(self.day, HistoryEvent::BrokeDown);
+                e.go_to(Destination::Desk, EMPLOYEE_SPEED, &self.nav);
+            }
+        }
+
+        // Employees feel locked in behind a barricaded window
+        if self.window_state == WindowState::Barricaded {
+            for mut e in self.iter_employees_mut() {
+                if matches!(e.state, EmployeeState::Alive) && !e.is_state_freezed {
+                    e.hope = (e.hope - self.rules.get_decay_rate()).max(0.);
+                }
+            }
+        }
     }
 
     pub fn apply_qte_effect(&mut self, effect: &QteEffect) {
@@ -193,10 +804,16 @@ impl Office {
 
         for mut e in self.iter_employees_mut() {
             if !e.is_state_freezed {
-                e.energy += effect.energy_delta;
-                e.satisfaction += effect.satisfaction_delta;
-                e.satiety += effect.satiety_delta;
-                e.hope += effect.hope_delta;
+                e.energy += e
+                    .personality
+                    .qte_reaction(Stat::Energy, effect.energy_delta);
+                e.satisfaction += e
+                    .personality
+                    .qte_reaction(Stat::Satisfaction, effect.satisfaction_delta);
+                e.satiety += e
+                    .personality
+                    .qte_reaction(Stat::Satiety, effect.satiety_delta);
+                e.hope += e.personality.qte_reaction(Stat::Hope, effect.hope_delta);
             }
         }
 
@@ -249,16 +866,30 @@ impl Office {
 
         let mut generated_money = 0.;
 
-        if self.iter_employees().any(|x| x.get_pos().x > OPEN_WINDOW_X) {
-            self.window_open = true;
-        } else {
-            self.window_open = false;
-        }
+        self.window_open = self.window_state == WindowState::Unlocked
+            && self.iter_employees().any(|x| x.get_pos().x > OPEN_WINDOW_X);
+
+        self.policies.tick(&self.employees, self.night, self.day);
+        self.update_zones();
+        self.wear_door();
+
+        let walkers = self
+            .iter_employees()
+            .filter(|e| e.is_walking())
+            .map(|e| (e.id, e.position))
+            .collect::<Vec<(u64, Vec2)>>();
 
         self.employees.retain(|e| {
             let mut e_borrow = e.borrow_mut();
 
-            generated_money += e_borrow.tick(self.door_state);
+            generated_money += e_borrow.tick(
+                self.door_state,
+                self.day,
+                self.night,
+                &self.rules,
+                &self.nav,
+                &walkers,
+            );
 
             if let EmployeeState::Clean = e_borrow.state {
                 removed_employees.push(e.clone());
@@ -269,23 +900,258 @@ impl Office {
         });
 
         self.money += generated_money;
+        self.earned += generated_money;
+
+        self.handle_window();
+        self.tick_fires();
+
+        for technician in &mut self.technicians {
+            technician.tick(&self.nav);
+        }
+        self.technicians.retain(|t| !t.is_gone());
+
+        self.spread_emotions();
+        self.mourn(&removed_employees);
 
         // Return spot to available spots
         for e in &removed_employees {
-            self.available_computers.push(e.borrow().computer.clone());
+            self.release_employee(&e.borrow());
         }
 
-        // Deselect dead employee
-        if let Some(selected) = &self.selected_employee {
-            for removed in removed_employees {
-                if Rc::ptr_eq(&removed, selected) {
-                    self.selected_employee = None;
-                    break;
+        // Deselect dead employee, and the ones who went home
+        self.selected_employees.retain(|selected| {
+            !removed_employees.iter().any(|r| Rc::ptr_eq(r, selected))
+                && !matches!(selected.borrow().state, EmployeeState::Home)
+        });
+    }
+
+    /// Sends the employees to the zone of their action, or back to their desk
+    fn update_zones(&mut self) {
+        for e in &self.employees {
+            let mut e = e.borrow_mut();
+            let id = e.id;
+
+            // Fleeing employees do not care about their action anymore
+            let managed = matches!(e.state, EmployeeState::Alive)
+                && matches!(e.destination, Destination::Desk | Destination::Spot(_));
+            let kind = if managed {
+                ZoneKind::for_action(e.action)
+            } else {
+                None
+            };
+
+            for zone in &mut self.zones {
+                if Some(zone.get_kind()) != kind {
+                    zone.leave(id);
                 }
             }
+
+            e.using_zone = false;
+
+            if !managed || matches!(e.action, EmployeeAction::ForcedSleep) {
+                continue;
+            }
+
+            let target = kind.and_then(|kind| {
+                let zone = self.zones.iter_mut().find(|z| z.get_kind() == kind)?;
+                zone.join(id);
+                zone.position_of(id).map(|pos| (pos, zone.is_using(id)))
+            });
+
+            if let Some((pos, using)) = target {
+                if e.destination != Destination::Spot(pos) {
+                    e.go_to(Destination::Spot(pos), EMPLOYEE_SPEED, &self.nav);
+                }
+                e.using_zone = using && e.has_arrived(Destination::Spot(pos));
+            } else if e.destination != Destination::Desk {
+                e.go_to(Destination::Desk, EMPLOYEE_SPEED, &self.nav);
+            }
         }
     }
 
+    pub fn iter_zones(&self) -> impl Iterator<Item = &Zone> {
+        self.zones.iter()
+    }
+
+    /// Crying employees drain the hope of their neighbours and happy ones cheer them up
+    fn spread_emotions(&self) {
+        let moods = self
+            .iter_employees()
+            .filter(|e| matches!(e.state, EmployeeState::Alive) && !e.is_state_freezed)
+            .map(|e| (e.id, e.position, e.hope < 0.2, e.satisfaction > 0.8))
+            .collect::<Vec<(u64, Vec2, bool, bool)>>();
+
+        for mut e in self.iter_employees_mut() {
+            if !matches!(e.state, EmployeeState::Alive) || e.is_state_freezed {
+                continue;
+            }
+
+            for &(id, position, crying, happy) in &moods {
+                if id == e.id || position.distance(e.position) > CONTAGION_RADIUS {
+                    continue;
+                }
+
+                let factor = self
+                    .relationships
+                    .get(e.id, id)
+                    .map_or(1., RelationshipKind::contagion_factor);
+
+                if crying {
+                    e.hope = CONTAGION_RATE.mul_add(-factor, e.hope).clamp(0., 1.);
+                }
+                if happy {
+                    e.satisfaction = CONTAGION_RATE.mul_add(factor, e.satisfaction).clamp(0., 1.);
+                }
+            }
+        }
+    }
+
+    /// Adds employees who died or left the office to the memorial, and lets their friends
+    /// grieve them
+    fn mourn(&mut self, removed_employees: &[Rc<RefCell<Employee>>]) {
+        let day = self.day;
+        let mut departed = Vec::new();
+
+        for e in self.employees.iter().chain(removed_employees.iter()) {
+            let mut e = e.borrow_mut();
+
+            let fate = match e.state {
+                EmployeeState::Dead if e.burned => Fate::Burned,
+                EmployeeState::Dead => Fate::Died,
+                EmployeeState::Falling => Fate::Jumped,
+                EmployeeState::Clean => Fate::Fled,
+                _ => continue,
+            };
+
+            if e.mourned {
+                continue;
+            }
+            e.mourned = true;
+
+            e.record(
+                day,
+                match fate {
+                    Fate::Died => HistoryEvent::Died,
+                    Fate::Fled => HistoryEvent::Fled,
+                    Fate::Jumped => HistoryEvent::Jumped,
+                    Fate::Burned => HistoryEvent::Burned,
+                },
+            );
+
+            self.memorial.push(MemorialEntry {
+                name: e.name.clone(),
+                fate,
+                day,
+                history: e.history.clone(),
+            });
+            departed.push(e.id);
+        }
+
+        for id in departed {
+            for (friend_id, kind) in self.relationships.iter_of(id) {
+                if kind != RelationshipKind::Friend {
+                    continue;
+                }
+
+                for mut e in self.iter_employees_mut() {
+                    if e.id == friend_id && !e.is_state_freezed {
+                        e.hope = (e.hope - GRIEF_HOPE).clamp(0., 1.);
+                        e.satisfaction = (e.satisfaction - GRIEF_SATISFACTION).clamp(0., 1.);
+                    }
+                }
+            }
+        }
+    }
+
+    pub const fn get_relationships(&self) -> &Relationships {
+        &self.relationships
+    }
+
+    /// Adds an event to the history of every employee affected by QTEs
+    pub fn record_event(&self, event: &HistoryEvent) {
+        let day = self.day;
+
+        for mut e in self.iter_employees_mut() {
+            if !e.is_state_freezed && matches!(e.state, EmployeeState::Alive) {
+                e.record(day, event.clone());
+            }
+        }
+    }
+
+    pub const fn get_day(&self) -> u32 {
+        self.day
+    }
+
+    /// New floors start on the day the company bought them
+    pub const fn set_day(&mut self, day: u32) {
+        self.day = day;
+    }
+
+    pub fn next_day(&mut self) {
+        self.day += 1;
+
+        self.money -= match self.window_state {
+            WindowState::Unlocked => 0.,
+            WindowState::Locked => WINDOW_LOCK_UPKEEP,
+            WindowState::Barricaded => WINDOW_BARRICADE_UPKEEP,
+        };
+        self.money -= self.policies.get_upkeep();
+    }
+
+    pub const fn get_policies(&self) -> &Policies {
+        &self.policies
+    }
+
+    pub const fn get_policies_mut(&mut self) -> &mut Policies {
+        &mut self.policies
+    }
+
+    /// At evening the employees at work go home if the door lets them through, the
+    /// others do overtime. Everyone comes back at dawn.
+    pub fn set_night(&mut self, night: bool) {
+        if night == self.night {
+            return;
+        }
+        self.night = night;
+
+        for e in &self.employees {
+            let mut e = e.borrow_mut();
+
+            if !night {
+                e.come_back(&self.nav);
+            } else if self.door_state.lets_through()
+                && matches!(e.state, EmployeeState::Alive)
+                && matches!(e.destination, Destination::Desk | Destination::Spot(_))
+                && e.action != EmployeeAction::ForcedSleep
+            {
+                e.go_home(&self.nav);
+                e.record(self.day, HistoryEvent::WentHome);
+            }
+        }
+    }
+
+    /// Number of employees who jumped out of the window so far
+    pub fn jumped_count(&self) -> usize {
+        self.fate_count(Fate::Jumped)
+    }
+
+    /// Number of employees of the memorial who met this fate
+    pub fn fate_count(&self, fate: Fate) -> usize {
+        self.memorial.iter().filter(|m| m.fate == fate).count()
+    }
+
+    pub const fn get_earned(&self) -> f32 {
+        self.earned
+    }
+
+    pub const fn hired_count(&self) -> usize {
+        self.hired
+    }
+
+    pub fn get_memorial(&self) -> &[MemorialEntry] {
+        &self.memorial
+    }
+
     pub fn is_game_over(&self) -> bool {
         let non_dead_employees_count = self
             .iter_employees()
@@ -316,17 +1182,53 @@ impl Office {
     }
 }
 
-fn feueur_particles() -> particles::EmitterConfig {
-    particles::EmitterConfig {
-        lifetime: 0.4,
-        lifetime_randomness: 0.1,
-        amount: 10,
-        initial_direction_spread: 0.5,
-        initial_velocity: 300.0,
-        atlas: Some(AtlasConfig::new(4, 4, 8..)),
-        size: 10.0,
-        blend_mode: BlendMode::Additive,
-        ..Default::default()
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComputerTier {
+    Basic,
+    Pro,
+    Workstation,
+}
+
+impl ComputerTier {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Basic => "Basique",
+            Self::Pro => "Pro",
+            Self::Workstation => "Station de travail",
+        }
+    }
+
+    /// Multiplier of the money generated by the employee working on it
+    pub const fn get_productivity(self) -> f32 {
+        match self {
+            Self::Basic => 1.,
+            Self::Pro => 1.5,
+            Self::Workstation => 2.2,
+        }
+    }
+
+    pub const fn get_price(self) -> f32 {
+        match self {
+            Self::Basic => 400.,
+            Self::Pro => 1200.,
+            Self::Workstation => 3000.,
+        }
+    }
+
+    pub const fn get_color(self) -> Color {
+        match self {
+            Self::Basic => WHITE,
+            Self::Pro => Color::new(0.7, 0.85, 1., 1.),
+            Self::Workstation => Color::new(1., 0.85, 0.4, 1.),
+        }
+    }
+
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Basic => Some(Self::Pro),
+            Self::Pro => Some(Self::Workstation),
+            Self::Workstation => None,
+        }
     }
 }
 
@@ -335,23 +1237,23 @@ pub struct Computer {
     pub broken: bool,
     pub spot: Vec2,
     pub rotation: f32,
-    pub emitter: Emitter,
+    pub tier: ComputerTier,
+    /// A technician is on their way or working on it
+    pub being_repaired: bool,
+    /// From 0 to 1, once the technician is working on it
+    pub repair_progress: f32,
 }
 
 impl Computer {
     pub fn new(position: Vec2, spot: Vec2, rotation: f32) -> Self {
-        let emitter = Emitter::new(EmitterConfig {
-            local_coords: false,
-            texture: Some(assets::FEUER_TEXTURE.clone()),
-            ..feueur_particles()
-        });
-
         Self {
             position,
             broken: false,
             spot,
             rotation,
-            emitter,
+            tier: ComputerTier::Basic,
+            being_repaired: false,
+            repair_progress: 0.,
         }
     }
 }
@@ -370,9 +1272,86 @@ pub enum EmployeeState {
     Clean,
     /// State for when the employee arrive
     Arriving,
+    /// Went home for the night
+    Home,
 }
 
-#[derive(Clone, Copy)]
+impl EmployeeState {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Alive => "En vie",
+            Self::Dead => "Mort",
+            Self::Falling => "En chute libre",
+            Self::Suicide => "Va se défenestrer",
+            Self::Clean => "Parti",
+            Self::Arriving => "Arrive",
+            Self::Home => "À la maison",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stat {
+    Satisfaction,
+    Hope,
+    Energy,
+    Satiety,
+}
+
+impl Stat {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Satisfaction => "Joie",
+            Self::Hope => "Espoir",
+            Self::Energy => "Energie",
+            Self::Satiety => "Sasiété",
+        }
+    }
+}
+
+/// What happens to an employee when one of their stats gets too low or too high
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Prediction {
+    ForcedSleep,
+    Starvation,
+    Despair,
+    BrokenComputer,
+    Flight,
+}
+
+impl Prediction {
+    pub const fn get_text(self) -> &'static str {
+        match self {
+            Self::ForcedSleep => "S'endormira de force",
+            Self::Starvation => "Mourra de faim",
+            Self::Despair => "Voudra sauter par la fenêtre",
+            Self::BrokenComputer => "Cassera son ordinateur",
+            Self::Flight => "Tentera de s'enfuir",
+        }
+    }
+}
+
+/// Where an employee is walking to
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Destination {
+    /// Their own desk
+    Desk,
+    /// Inside of the office door
+    Door,
+    /// Inside of the office door, on the way home
+    HomeDoor,
+    /// Outside of the office door
+    DoorOutside,
+    /// End of the corridor, out of the building
+    Exit,
+    Window,
+    /// Any point of the office, like a slot of a zone
+    Spot(Vec2),
+    /// Out of the building for the night
+    Home,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EmployeeAction {
     None,
     /// Satisfaction
@@ -387,8 +1366,63 @@ pub enum EmployeeAction {
     ForcedSleep,
 }
 
+/// Quick selections of the employees in need
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionFilter {
+    Hungry,
+    Exhausted,
+    Hopeless,
+}
+
+impl SelectionFilter {
+    pub const ALL: [Self; 3] = [Self::Hungry, Self::Exhausted, Self::Hopeless];
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Hungry => "Affamés",
+            Self::Exhausted => "Épuisés",
+            Self::Hopeless => "Désespérés",
+        }
+    }
+
+    pub fn matches(self, employee: &Employee) -> bool {
+        match self {
+            Self::Hungry => employee.satiety < LOW_STAT,
+            Self::Exhausted => employee.energy < LOW_STAT,
+            Self::Hopeless => employee.hope < LOW_STAT,
+        }
+    }
+}
+
+impl EmployeeAction {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::None => "Travaille",
+            Self::Break => "En pause",
+            Self::Eat => "Mange",
+            Self::Sleep => "Dort",
+            Self::FamilyCall => "Appelle sa famille",
+            Self::ForcedSleep => "Dort de force",
+        }
+    }
+
+    /// Stat the action replenishes
+    pub const fn get_stat(self) -> Option<Stat> {
+        match self {
+            Self::None => None,
+            Self::Break => Some(Stat::Satisfaction),
+            Self::Eat => Some(Stat::Satiety),
+            Self::Sleep | Self::ForcedSleep => Some(Stat::Energy),
+            Self::FamilyCall => Some(Stat::Hope),
+        }
+    }
+}
+
 pub struct Employee {
+    id: u64,
     name: String,
+    identity: Identity,
+    personality: Personality,
     satisfaction: f32,
     hope: f32,
     energy: f32,
@@ -401,7 +1435,11 @@ pub struct Employee {
     computer: Rc<RefCell<Computer>>,
     rotation: f32,
     pub state: EmployeeState,
-    movment_step: usize,
+    destination: Destination,
+    path: VecDeque<Vec2>,
+    speed: f32,
+    blocked_ticks: u32,
+    using_zone: bool,
     pub action: EmployeeAction,
     pub z_emitter: Emitter,
     pub cry_emitter: Emitter,
@@ -412,6 +1450,12 @@ pub struct Employee {
     pub lightning_emitter: Emitter,
     pub heart_emitter: Emitter,
     pub is_state_freezed: bool,
+    mourned: bool,
+    burned: bool,
+    history: Vec<HistoryEntry>,
+    last_action: EmployeeAction,
+    /// Stayed in the office at night
+    overtime: bool,
 }
 
 fn sleep_particles() -> particles::EmitterConfig {
@@ -527,7 +1571,7 @@ fn mad2_particles() -> particles::EmitterConfig {
 }
 
 impl Employee {
-    pub fn new(computer: Rc<RefCell<Computer>>) -> Self {
+    pub fn new(id: u64, computer: Rc<RefCell<Computer>>, identity: Identity) -> Self {
         let z_emitter = Emitter::new(EmitterConfig {
             local_coords: false,
             texture: Some(assets::Z_TEXTURE.clone()),
@@ -576,10 +1620,11 @@ impl Employee {
             ..heart_paricles()
         });
 
-        let name = NAMES[gen_range(0, NAMES.len())].to_owned();
-
         Self {
-            name,
+            id,
+            name: identity.full_name(),
+            identity,
+            personality: Personality::random(),
             satisfaction: rand::gen_range(0.3, 0.7),
             hope: rand::gen_range(0.3, 0.7),
             energy: rand::gen_range(0.3, 0.7),
@@ -588,11 +1633,15 @@ impl Employee {
             hope_factor: rand::gen_range(0.7, 1.3),
             energy_factor: rand::gen_range(0.7, 1.3),
             satiety_factor: rand::gen_range(0.7, 1.3),
-            position: Vec2::new(220., 750.),
+            position: ENTRANCE,
             computer,
             rotation: 0.,
             state: EmployeeState::Arriving,
-            movment_step: 0,
+            destination: Destination::DoorOutside,
+            path: VecDeque::new(),
+            speed: EMPLOYEE_RUNNING_SPEED,
+            blocked_ticks: 0,
+            using_zone: false,
             action: EmployeeAction::None,
             z_emitter,
             cry_emitter,
@@ -603,40 +1652,76 @@ impl Employee {
             lightning_emitter,
             heart_emitter,
             is_state_freezed: false,
+            mourned: false,
+            burned: false,
+            history: Vec::new(),
+            last_action: EmployeeAction::None,
+            overtime: false,
         }
     }
 
     #[must_use]
-    pub fn tick(&mut self, door_state: DoorState) -> f32 {
-        if let EmployeeState::Clean = self.state {
-            return 0.;
+    pub fn tick(
+        &mut self,
+        door_state: DoorState,
+        day: u32,
+        night: bool,
+        rules: &Rules,
+        nav: &NavGrid,
+        walkers: &[(u64, Vec2)],
+    ) -> f32 {
+        match self.state {
+            EmployeeState::Clean => return 0.,
+            // Employees rest at home until the morning
+            EmployeeState::Home => {
+                if self.energy < HOME_MAX_ENERGY {
+                    self.energy = rules
+                        .replenish_rate
+                        .mul_add(HOME_REST_FACTOR, self.energy)
+                        .min(HOME_MAX_ENERGY);
+                }
+                return 0.;
+            }
+            _ => (),
         }
 
+        self.overtime = night;
+
+        let previous_stats = self.get_stats();
+
         if !self.is_state_freezed {
-            self.satisfaction -= BASE_DECAY_RATE * self.satisfaction_factor;
-            if let DoorState::Closed = door_state {
-                self.hope -= BASE_DECAY_RATE * self.hope_factor * 4.;
-            } else {
-                self.hope += BASE_DECAY_RATE * self.hope_factor * 4.;
-            }
-            self.energy -= BASE_DECAY_RATE * self.energy_factor;
-            if self.satiety > 0.9 {
-                self.energy -= BASE_DECAY_RATE * self.energy_factor * 5.;
-            }
-            self.satiety -= BASE_DECAY_RATE * self.satiety_factor;
+            let [satisfaction, hope, energy, satiety] =
+                previous_stats.map(|(stat, _)| self.decay_rate(stat, door_state, rules));
+
+            self.satisfaction -= satisfaction;
+            self.hope -= hope;
+            self.energy -= energy;
+            self.satiety -= satiety;
         }
 
+        let personality = &self.personality;
+        let replenish_rate = rules.replenish_rate;
+
+        // Actions only replenish once the employee got a slot in their zone
         match self.action {
             EmployeeAction::None => (),
+            _ if !self.using_zone && self.action != EmployeeAction::ForcedSleep => (),
             EmployeeAction::Break => {
                 if !self.is_state_freezed {
-                    self.satisfaction += REPLENISH_RATE;
+                    self.satisfaction +=
+                        replenish_rate * personality.replenish_factor(Stat::Satisfaction);
                 }
             }
-            EmployeeAction::Eat => self.satiety += REPLENISH_RATE,
-            EmployeeAction::Sleep => self.energy += REPLENISH_RATE,
-            EmployeeAction::FamilyCall => self.hope += REPLENISH_RATE,
-            EmployeeAction::ForcedSleep => self.energy += REPLENISH_RATE / 2.,
+            EmployeeAction::Eat => {
+                self.satiety += replenish_rate * personality.replenish_factor(Stat::Satiety);
+            }
+            EmployeeAction::Sleep => {
+                self.energy += replenish_rate * personality.replenish_factor(Stat::Energy);
+            }
+            EmployeeAction::FamilyCall => {
+                self.hope += replenish_rate * personality.replenish_factor(Stat::Hope);
+            }
+            EmployeeAction::ForcedSleep => self.energy += replenish_rate / 2.,
         }
 
         self.satisfaction = self.satisfaction.clamp(0., 1.);
@@ -652,201 +1737,79 @@ impl Employee {
             self.action = EmployeeAction::None
         }
 
+        if matches!(self.state, EmployeeState::Alive) {
+            if self.action != self.last_action {
+                self.record(day, HistoryEvent::Action(self.action));
+            }
+
+            for ((stat, value), (_, previous)) in self.get_stats().into_iter().zip(previous_stats) {
+                if value <= 0. && previous > 0. {
+                    self.record(day, HistoryEvent::StatExtreme(stat, false));
+                } else if value >= 1. && previous < 1. {
+                    self.record(day, HistoryEvent::StatExtreme(stat, true));
+                }
+            }
+        }
+        self.last_action = self.action;
+
         if self.satiety == 0. {
             self.state = EmployeeState::Dead
         }
 
-        if ((self.hope == 0.) || matches!(self.state, EmployeeState::Suicide))
-            && (self.movment_step == 3)
-        {
+        if self.hope == 0. && self.is_at_desk() {
             self.state = EmployeeState::Suicide;
-            if self.movment_step == 3 {
-                self.movment_step = 0;
-            }
         }
 
-        if (self.satisfaction == 0. || self.energy == 1.) && self.movment_step == 3 {
+        if (self.satisfaction <= self.personality.break_computer_satisfaction()
+            || self.energy >= 1.)
+            && self.is_at_desk()
+            && !self.computer.borrow().broken
+        {
             self.computer.borrow_mut().broken = true;
+            self.record(day, HistoryEvent::BrokeComputer);
         }
 
-        if (self.satisfaction == 0. || self.hope == 1.) && self.movment_step == 3 {
-            self.movment_step = 4;
+        if (self.satisfaction <= self.personality.flee_satisfaction()
+            || self.hope >= self.personality.flee_hope())
+            && self.is_at_desk()
+        {
+            self.go_to(Destination::Door, EMPLOYEE_RUNNING_SPEED, nav);
         }
 
-        let spot = self.computer.borrow().spot;
-
         if let EmployeeState::Alive = self.state {
-            let index_x = SPOT_X.iter().position(|x: &f32| *x == spot.x).unwrap();
-
-            match self.movment_step {
-                0 => {
-                    if self.position.x > MIDDLE_SPOT_X[index_x] {
-                        self.position.x = MIDDLE_SPOT_X[index_x];
-                        self.movment_step += 1;
-                    } else {
-                        self.position.x += EMPLOYEE_SPEED;
-                        self.rotation = 0.;
-                    }
-                }
-                1 => {
-                    if spot.y < MIDDLE_LANE {
-                        if self.position.y > spot.y {
-                            self.position.y -= EMPLOYEE_SPEED;
-                            self.rotation = -PI / 2.;
-                        } else {
-                            self.position.y = spot.y;
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.y < spot.y {
-                            self.position.y += EMPLOYEE_SPEED;
-                            self.rotation = PI / 2.;
-                        } else {
-                            self.position.y = spot.y;
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                2 => {
-                    if index_x % 2 == 0 {
-                        if self.position.x < spot.x {
-                            self.position.x += EMPLOYEE_SPEED;
-                            self.rotation = 0.;
-                        } else {
-                            self.position.x = spot.x;
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.x > spot.x {
-                            self.position.x -= EMPLOYEE_SPEED;
-                            self.rotation = PI;
-                        } else {
-                            self.position.x = spot.x;
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                3 => self.position = spot,
-                4 => {
-                    if index_x % 2 == 0 {
-                        if self.position.x > MIDDLE_SPOT_X[index_x] {
-                            self.position.x -= EMPLOYEE_RUNNING_SPEED;
-                            self.rotation = PI;
-                        } else {
-                            self.position.x = MIDDLE_SPOT_X[index_x];
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.x < MIDDLE_SPOT_X[index_x] {
-                            self.position.x += EMPLOYEE_RUNNING_SPEED;
-                            self.rotation = 0.;
-                        } else {
-                            self.position.x = MIDDLE_SPOT_X[index_x];
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                5 => {
-                    if spot.y < MIDDLE_LANE {
-                        if self.position.y < MIDDLE_LANE {
-                            self.position.y += EMPLOYEE_RUNNING_SPEED;
-                            self.rotation = PI / 2.;
-                        } else {
-                            self.position.y = MIDDLE_LANE;
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.y > MIDDLE_LANE {
-                            self.position.y -= EMPLOYEE_RUNNING_SPEED;
-                            self.rotation = -PI / 2.;
-                        } else {
-                            self.position.y = MIDDLE_LANE;
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                6 => {
-                    if self.position.x > DOOR_X_INSIDE {
-                        self.position.x -= EMPLOYEE_RUNNING_SPEED;
-                        self.rotation = PI
-                    } else if let DoorState::Open = door_state {
-                        self.movment_step += 1;
-                    } else if self.hope < 0.9 && self.satisfaction > 0.1 {
-                        self.movment_step = 0
-                    } else {
-                        self.position.x = DOOR_X_INSIDE;
-                    }
-                }
-                7 => {
-                    if self.position.x > 250. {
-                        self.position.x -= EMPLOYEE_RUNNING_SPEED;
-                    } else {
-                        self.position.x = 250.;
-                        self.movment_step += 1;
-                    }
+            self.walk(walkers);
+
+            if self.has_arrived(Destination::Door) {
+                if door_state.lets_through() {
+                    self.go_to(Destination::Exit, EMPLOYEE_RUNNING_SPEED, nav);
+                } else if self.hope < 0.9 && self.satisfaction > 0.1 {
+                    self.go_to(Destination::Desk, EMPLOYEE_SPEED, nav);
                 }
-                8 => {
-                    self.rotation = -PI / 2.;
-                    self.position.y -= EMPLOYEE_RUNNING_SPEED;
-                    if self.position.y < 0. {
-                        self.clean();
-                    }
+            } else if self.has_arrived(Destination::HomeDoor) {
+                // Locked in for the night, back to work for overtime
+                if door_state.lets_through() {
+                    self.go_to(Destination::Home, EMPLOYEE_SPEED, nav);
+                } else {
+                    self.go_to(Destination::Desk, EMPLOYEE_SPEED, nav);
                 }
-                _ => (),
+            } else if self.has_arrived(Destination::Exit) {
+                self.clean();
+            } else if self.has_arrived(Destination::Home) {
+                self.state = EmployeeState::Home;
+            } else if self.is_at_desk() {
+                let computer = self.computer.borrow();
+                let facing = computer.position - computer.spot;
+                self.position = computer.spot;
+                self.rotation = facing.y.atan2(facing.x);
             }
         } else if let EmployeeState::Suicide = self.state {
-            let index_x = SPOT_X.iter().position(|x: &f32| *x == spot.x).unwrap();
-
-            match self.movment_step {
-                0 => {
-                    if index_x % 2 == 0 {
-                        if self.position.x > MIDDLE_SPOT_X[index_x] {
-                            self.position.x -= EMPLOYEE_SPEED;
-                            self.rotation = PI;
-                        } else {
-                            self.position.x = MIDDLE_SPOT_X[index_x];
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.x < MIDDLE_SPOT_X[index_x] {
-                            self.position.x += EMPLOYEE_SPEED;
-                            self.rotation = 0.;
-                        } else {
-                            self.position.x = MIDDLE_SPOT_X[index_x];
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                1 => {
-                    if spot.y < MIDDLE_LANE {
-                        if self.position.y < MIDDLE_LANE {
-                            self.position.y += EMPLOYEE_SPEED;
-                            self.rotation = PI / 2.;
-                        } else {
-                            self.position.y = MIDDLE_LANE;
-                            self.movment_step += 1;
-                        }
-                    } else {
-                        if self.position.y > MIDDLE_LANE {
-                            self.position.y -= EMPLOYEE_SPEED;
-                            self.rotation = -PI / 2.;
-                        } else {
-                            self.position.y = MIDDLE_LANE;
-                            self.movment_step += 1;
-                        }
-                    }
-                }
-                2 => {
-                    if self.position.x < WINDOW_X {
-                        self.position.x += EMPLOYEE_SPEED;
-                        self.rotation = 0.
-                    } else {
-                        self.position.x = WINDOW_X;
-                        self.state = EmployeeState::Falling;
-                    }
-                }
-                _ => (),
+            if self.destination != Destination::Window {
+                self.go_to(Destination::Window, EMPLOYEE_SPEED, nav);
             }
+
+            self.walk(walkers);
+
+            // The office decides whether the window lets them through
         } else if let EmployeeState::Falling = self.state {
             if self.position.y < OFFICE_HEIGHT as f32 + 50. {
                 self.position += vec2(21., 84.) / SPEED_FALL;
@@ -854,46 +1817,25 @@ impl Employee {
                 self.clean();
             }
         } else if let EmployeeState::Arriving = self.state {
-            match self.movment_step {
-                0 => {
-                    if self.position.y > MIDDLE_LANE {
-                        self.position.y -= EMPLOYEE_RUNNING_SPEED;
-                        self.rotation = -PI / 2.;
-                    } else {
-                        self.movment_step += 1;
-                    }
-                }
-                1 => {
-                    if self.position.x < DOOR_X_OUTSIDE {
-                        self.position.x += EMPLOYEE_RUNNING_SPEED;
-                        self.rotation = 0.0;
-                    } else if let DoorState::Open = door_state {
-                        self.movment_step += 1;
-                    } else {
-                        self.position.x = DOOR_X_OUTSIDE;
-                    }
-                }
-                2 => {
-                    if self.position.x < DOOR_X_INSIDE {
-                        self.position.x += EMPLOYEE_RUNNING_SPEED;
-                    } else {
-                        self.position.x = DOOR_X_INSIDE;
-                        self.movment_step = 0;
-                        self.state = EmployeeState::Alive;
-                    }
-                }
-                _ => (),
+            self.walk(walkers);
+
+            if self.has_arrived(Destination::DoorOutside) && door_state.lets_through() {
+                self.state = EmployeeState::Alive;
+                self.go_to(Destination::Desk, EMPLOYEE_SPEED, nav);
             }
         }
 
         if let EmployeeState::Alive = self.state {
-            if self.computer.borrow().broken || self.movment_step != 3 {
+            let computer = self.computer.borrow();
+            if computer.broken || !self.is_at_desk() {
                 0.
             } else {
+                let overtime = if self.overtime { OVERTIME_PAY } else { 1. };
+
                 if self.satisfaction == 1. {
-                    0.01
+                    0.01 * computer.tier.get_productivity() * overtime
                 } else {
-                    0.1
+                    0.1 * computer.tier.get_productivity() * overtime
                 }
             }
         } else {
@@ -901,14 +1843,219 @@ impl Employee {
         }
     }
 
+    fn destination_pos(&self, destination: Destination) -> Vec2 {
+        match destination {
+            Destination::Desk => self.computer.borrow().spot,
+            Destination::Door | Destination::HomeDoor => Vec2::new(DOOR_X_INSIDE, MIDDLE_LANE),
+            Destination::DoorOutside => Vec2::new(DOOR_X_OUTSIDE, MIDDLE_LANE),
+            Destination::Exit | Destination::Home => EXIT,
+            Destination::Window => Vec2::new(WINDOW_X, MIDDLE_LANE),
+            Destination::Spot(pos) => pos,
+        }
+    }
+
+    /// Computes the path to the destination, or walks straight to it if it
+    /// can not be reached
+    pub fn go_to(&mut self, destination: Destination, speed: f32, nav: &NavGrid) {
+        let target = self.destination_pos(destination);
+
+        self.destination = destination;
+        self.speed = speed;
+        self.blocked_ticks = 0;
+        self.path = nav
+            .find_path(self.position, target)
+            .unwrap_or_else(|| vec![target])
+            .into();
+    }
+
+    /// Moves along the path, waiting for the walking employees in front with
+    /// a lower id
+    fn walk(&mut self, walkers: &[(u64, Vec2)]) {
+        let Some(&target) = self.path.front() else {
+            return;
+        };
+
+        // Collapsed employees do not walk
+        if self.action == EmployeeAction::ForcedSleep {
+            return;
+        }
+
+        let direction = target - self.position;
+
+        let blocked = walkers.iter().any(|&(id, pos)| {
+            id < self.id
+                && pos.distance(self.position) < PERSONAL_SPACE
+                && (pos - self.position).dot(direction) > 0.
+        });
+
+        if blocked && self.blocked_ticks < MAX_BLOCKED_TICKS {
+            self.blocked_ticks += 1;
+            return;
+        }
+        self.blocked_ticks = 0;
+
+        if let Some(rotation) = follow_path(&mut self.position, &mut self.path, self.speed) {
+            self.rotation = rotation;
+        }
+    }
+
+    /// Leaves the office for the night, if the door is still open once there
+    pub fn go_home(&mut self, nav: &NavGrid) {
+        self.action = EmployeeAction::None;
+        self.go_to(Destination::HomeDoor, EMPLOYEE_SPEED, nav);
+    }
+
+    /// Comes back in the morning, or goes back to work if still on the way home
+    pub fn come_back(&mut self, nav: &NavGrid) {
+        match self.state {
+            EmployeeState::Home => {
+                self.state = EmployeeState::Arriving;
+                self.action = EmployeeAction::None;
+                self.position = ENTRANCE;
+                self.go_to(Destination::DoorOutside, EMPLOYEE_SPEED, nav);
+            }
+            EmployeeState::Alive
+                if matches!(self.destination, Destination::HomeDoor | Destination::Home) =>
+            {
+                self.go_to(Destination::Desk, EMPLOYEE_SPEED, nav);
+            }
+            _ => (),
+        }
+    }
+
+    fn has_arrived(&self, destination: Destination) -> bool {
+        self.destination == destination && self.path.is_empty()
+    }
+
+    /// Fleeing and stuck behind the closed door
+    pub fn is_pushing_door(&self, door_state: DoorState) -> bool {
+        matches!(self.state, EmployeeState::Alive)
+            && matches!(door_state, DoorState::Closed)
+            && self.has_arrived(Destination::Door)
+    }
+
+    pub fn is_at_desk(&self) -> bool {
+        matches!(self.state, EmployeeState::Alive) && self.has_arrived(Destination::Desk)
+    }
+
+    /// Whether the employee is doing their action in its zone, and not
+    /// walking to it or waiting in the queue
+    pub const fn is_using_zone(&self) -> bool {
+        self.using_zone
+    }
+
+    pub fn is_walking(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    pub fn get_path(&self) -> impl Iterator<Item = &Vec2> {
+        self.path.iter()
+    }
+
+    pub fn record(&mut self, day: u32, event: HistoryEvent) {
+        self.history.push(HistoryEntry { day, event });
+    }
+
+    pub fn get_history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    /// How much the stat goes down each tick, negative when it goes up
+    fn decay_rate(&self, stat: Stat, door_state: DoorState, rules: &Rules) -> f32 {
+        let personality = &self.personality;
+        let base = rules.get_decay_rate();
+
+        match stat {
+            Stat::Satisfaction => base * self.satisfaction_factor * personality.decay_factor(stat),
+            Stat::Hope => {
+                if matches!(door_state, DoorState::Closed) {
+                    base * self.hope_factor
+                        * 4.
+                        * personality.decay_factor(stat)
+                        * personality.door_closed_hope_factor()
+                } else {
+                    -base * self.hope_factor * 4. * personality.door_open_hope_factor()
+                }
+            }
+            Stat::Energy => {
+                let mut rate = base * self.energy_factor * personality.decay_factor(stat);
+                if self.satiety > 0.9 {
+                    rate += base * self.energy_factor * 5.;
+                }
+                if self.overtime {
+                    rate *= OVERTIME_ENERGY_FACTOR;
+                }
+                rate
+            }
+            Stat::Satiety => base * self.satiety_factor * personality.decay_factor(stat),
+        }
+    }
+
+    /// The next thing that will happen to the employee if nothing changes,
+    /// and in how many ticks
+    pub fn get_prediction(
+        &self,
+        door_state: DoorState,
+        rules: &Rules,
+    ) -> Option<(Prediction, f32)> {
+        if !matches!(self.state, EmployeeState::Alive) || self.is_state_freezed {
+            return None;
+        }
+
+        let replenished = self.action.get_stat();
+
+        self.get_stats()
+            .into_iter()
+            .filter(|(stat, _)| Some(*stat) != replenished)
+            .filter_map(|(stat, value)| {
+                let rate = self.decay_rate(stat, door_state, rules);
+
+                let (prediction, distance) = match stat {
+                    Stat::Energy => (Prediction::ForcedSleep, value),
+                    Stat::Satiety => (Prediction::Starvation, value),
+                    Stat::Satisfaction => (
+                        Prediction::BrokenComputer,
+                        value - self.personality.break_computer_satisfaction(),
+                    ),
+                    Stat::Hope if rate > 0. => (Prediction::Despair, value),
+                    Stat::Hope => (Prediction::Flight, value - self.personality.flee_hope()),
+                };
+
+                let ticks = distance / rate;
+                (ticks.is_finite() && ticks >= 0.).then_some((prediction, ticks))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    pub const fn get_stats(&self) -> [(Stat, f32); 4] {
+        [
+            (Stat::Satisfaction, self.satisfaction),
+            (Stat::Hope, self.hope),
+            (Stat::Energy, self.energy),
+            (Stat::Satiety, self.satiety),
+        ]
+    }
+
     pub fn clean(&mut self) {
         self.state = EmployeeState::Clean;
     }
 
+    pub const fn get_id(&self) -> u64 {
+        self.id
+    }
+
     pub fn get_name(&self) -> &str {
         &self.name
     }
 
+    pub const fn get_identity(&self) -> &Identity {
+        &self.identity
+    }
+
+    pub const fn get_personality(&self) -> &Personality {
+        &self.personality
+    }
+
     pub fn get_satisfaction(&self) -> f32 {
         self.satisfaction
     }
@@ -925,6 +2072,19 @@ impl Employee {
         self.satiety
     }
 
+    pub const fn get_stat(&self, stat: Stat) -> f32 {
+        match stat {
+            Stat::Satisfaction => self.satisfaction,
+            Stat::Hope => self.hope,
+            Stat::Energy => self.energy,
+            Stat::Satiety => self.satiety,
+        }
+    }
+
+    pub const fn is_state_freezed(&self) -> bool {
+        self.is_state_freezed
+    }
+
     pub fn get_pos(&self) -> Vec2 {
         self.position
     }
@@ -0,0 +1,112 @@
+use std::str::FromStr;
+
+/// Words of the lines of a text file, with their number from 1. Empty lines
+/// and `#` comments are skipped.
+fn lines(text: &str) -> impl Iterator<Item = (usize, Vec<&str>)> {
+    text.lines()
+        .enumerate()
+        .map(|(n, line)| (n + 1, line.split_whitespace().collect::<Vec<&str>>()))
+        .filter(|(_, words)| words.first().is_some_and(|w| !w.starts_with('#')))
+}
+
+fn at_line(n: usize, error: &str) -> String {
+    format!("ligne {n} : {error}")
+}
+
+/// Reads each line of the file, and stops at the first one that can not be read
+pub fn parse_lines<'a>(
+    text: &'a str,
+    mut parse_line: impl FnMut(&[&'a str]) -> Result<(), String>,
+) -> Result<(), String> {
+    for (n, words) in lines(text) {
+        parse_line(&words).map_err(|error| at_line(n, &error))?;
+    }
+
+    Ok(())
+}
+
+/// Reads each line of the file, and returns the errors of the lines skipped
+pub fn parse_lines_skipping<'a>(
+    text: &'a str,
+    mut parse_line: impl FnMut(&[&'a str]) -> Result<(), String>,
+) -> Vec<String> {
+    lines(text)
+        .filter_map(|(n, words)| parse_line(&words).err().map(|error| at_line(n, &error)))
+        .collect()
+}
+
+pub fn unknown_line() -> String {
+    "ligne incomprise".to_owned()
+}
+
+pub fn number<T: FromStr>(word: &str) -> Result<T, String> {
+    word.parse::<T>()
+        .map_err(|_| format!("nombre invalide \"{word}\""))
+}
+
+/// A number that is neither infinite nor NaN
+pub fn finite(word: &str) -> Result<f32, String> {
+    number::<f32>(word)
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| format!("nombre invalide \"{word}\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_empty_lines_and_comments() {
+        let mut read = Vec::new();
+        let text = "# Commentaire\n\na 1\n   \n  #autre\nb  2 3\n";
+
+        parse_lines(text, |words| {
+            read.push(words.join(" "));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(read, vec!["a 1", "b 2 3"]);
+    }
+
+    #[test]
+    fn errors_give_the_line() {
+        let result = parse_lines("a\n\nb", |words| match words {
+            ["a"] => Ok(()),
+            _ => Err(unknown_line()),
+        });
+
+        assert_eq!(result, Err("ligne 3 : ligne incomprise".to_owned()));
+    }
+
+    #[test]
+    fn skipping_keeps_reading_after_an_error() {
+        let mut read = Vec::new();
+
+        let errors = parse_lines_skipping("x\n1\ny\n2", |words| {
+            read.push(number::<u32>(words[0])?);
+            Ok(())
+        });
+
+        assert_eq!(read, vec![1, 2]);
+        assert_eq!(
+            errors,
+            vec![
+                "ligne 1 : nombre invalide \"x\"".to_owned(),
+                "ligne 3 : nombre invalide \"y\"".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(number::<u64>("42"), Ok(42));
+        assert!(number::<u64>("-1").is_err());
+        assert_eq!(finite("-2.5"), Ok(-2.5));
+
+        for word in ["nan", "NaN", "inf", "-inf", "infinity", "douze"] {
+            assert_eq!(finite(word), Err(format!("nombre invalide \"{word}\"")));
+        }
+    }
+}
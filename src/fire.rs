@@ -0,0 +1,100 @@
+use std::{cell::RefCell, rc::Rc};
+
+use macroquad::math::Vec2;
+use macroquad_particles::{self as particles, AtlasConfig, BlendMode, Emitter, EmitterConfig};
+
+use crate::{assets, employee::Computer};
+
+pub const EXTINGUISH_COST: f32 = 100.;
+
+/// Chance per tick for a broken computer to catch fire
+pub const IGNITION_CHANCE: f32 = 1. / 1200.;
+/// Chance per tick for a big fire to spread to a computer nearby
+pub const SPREAD_CHANCE: f32 = 1. / 400.;
+/// Computers of the adjacent desks are under this distance
+pub const SPREAD_DISTANCE: f32 = 230.;
+pub const SPREAD_INTENSITY: f32 = 0.6;
+/// Intensity at which the fire alarm goes off
+pub const ALARM_INTENSITY: f32 = 0.4;
+
+/// Radius at full intensity under which employees are hurt
+pub const FIRE_RADIUS: f32 = 180.;
+pub const FIRE_DAMAGE: f32 = 0.002;
+/// Employees this close to a fire above `LETHAL_INTENSITY` can die
+pub const LETHAL_RADIUS: f32 = 90.;
+pub const LETHAL_INTENSITY: f32 = 0.8;
+pub const LETHAL_CHANCE: f32 = 1. / 300.;
+
+const GROWTH_RATE: f32 = 0.0005;
+
+fn fire_particles() -> particles::EmitterConfig {
+    particles::EmitterConfig {
+        lifetime: 0.4,
+        lifetime_randomness: 0.1,
+        amount: 10,
+        initial_direction_spread: 0.5,
+        initial_velocity: 300.0,
+        atlas: Some(AtlasConfig::new(4, 4, 8..)),
+        size: 10.0,
+        blend_mode: BlendMode::Additive,
+        ..Default::default()
+    }
+}
+
+/// A fire burning on a computer. It grows until it is extinguished.
+pub struct Fire {
+    computer: Rc<RefCell<Computer>>,
+    intensity: f32,
+    alarm_raised: bool,
+    pub emitter: Emitter,
+}
+
+impl Fire {
+    pub fn new(computer: Rc<RefCell<Computer>>) -> Self {
+        let emitter = Emitter::new(EmitterConfig {
+            local_coords: false,
+            texture: Some(assets::FEUER_TEXTURE.clone()),
+            ..fire_particles()
+        });
+
+        computer.borrow_mut().broken = true;
+
+        Self {
+            computer,
+            intensity: 0.1,
+            alarm_raised: false,
+            emitter,
+        }
+    }
+
+    /// Grows the fire, and returns whether the fire alarm should go off
+    pub fn tick(&mut self) -> bool {
+        self.intensity = (self.intensity + GROWTH_RATE).min(1.);
+        self.emitter.config.size = 30.0f32.mul_add(self.intensity, 10.);
+        self.emitter.config.initial_velocity = 300.0f32.mul_add(self.intensity, 100.);
+
+        if !self.alarm_raised && self.intensity >= ALARM_INTENSITY {
+            self.alarm_raised = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub const fn get_computer(&self) -> &Rc<RefCell<Computer>> {
+        &self.computer
+    }
+
+    pub fn get_pos(&self) -> Vec2 {
+        self.computer.borrow().position
+    }
+
+    pub const fn get_intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    /// Radius under which the fire hurts employees
+    pub fn get_radius(&self) -> f32 {
+        FIRE_RADIUS * self.intensity
+    }
+}
@@ -0,0 +1,83 @@
+/// How much harder an endless run gets each day
+const ENDLESS_RAMP: f32 = 0.08;
+/// Days between two milestones of an endless run
+pub const ENDLESS_MILESTONE_DAYS: u32 = 5;
+/// Multiplier of the score gained each day survived in an endless run
+const ENDLESS_SCORE_PER_DAY: f32 = 0.1;
+
+/// The game changes its name once the door closes: the modes are the two sides
+/// of the same office, and the crunch that never ends
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameMode {
+    MyLittleOffice,
+    /// QTEs come twice as often
+    CrunchSimulator,
+    /// Gets harder day after day
+    Endless,
+}
+
+impl GameMode {
+    pub const ALL: [Self; 3] = [Self::MyLittleOffice, Self::CrunchSimulator, Self::Endless];
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::MyLittleOffice => "My Little Office",
+            Self::CrunchSimulator => "Crunch Simulator",
+            Self::Endless => "Crunch sans fin",
+        }
+    }
+
+    /// Name in the saved files
+    pub const fn get_key(self) -> &'static str {
+        match self {
+            Self::MyLittleOffice => "office",
+            Self::CrunchSimulator => "crunch",
+            Self::Endless => "endless",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.get_key() == key)
+    }
+
+    pub const fn next(self) -> Self {
+        match self {
+            Self::MyLittleOffice => Self::CrunchSimulator,
+            Self::CrunchSimulator => Self::Endless,
+            Self::Endless => Self::MyLittleOffice,
+        }
+    }
+
+    /// Multiplier of the time between two QTEs
+    pub const fn get_qte_period_factor(self) -> f32 {
+        match self {
+            Self::MyLittleOffice | Self::Endless => 1.,
+            Self::CrunchSimulator => 0.5,
+        }
+    }
+
+    /// How much harder the run got on that day: the stats decay faster, QTEs
+    /// come more often with less time to answer, and the big boss asks for more
+    pub const fn get_escalation(self, day: u32) -> f32 {
+        match self {
+            Self::Endless => (day.saturating_sub(1) as f32).mul_add(ENDLESS_RAMP, 1.),
+            _ => 1.,
+        }
+    }
+
+    /// Milestones reached on that day, from 0 on the first one
+    pub const fn get_milestone(self, day: u32) -> Option<u32> {
+        match self {
+            Self::Endless => Some(day.saturating_sub(1) / ENDLESS_MILESTONE_DAYS),
+            _ => None,
+        }
+    }
+
+    /// Multiplier of the score of a run that lasted that many days
+    pub const fn get_score_factor(self, days: u32) -> f32 {
+        match self {
+            Self::Endless => (days as f32).mul_add(ENDLESS_SCORE_PER_DAY, 1.),
+            _ => 1.,
+        }
+    }
+}
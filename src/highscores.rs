@@ -0,0 +1,294 @@
+use std::{cmp::Reverse, fmt::Write};
+
+use macroquad::miniquad::date;
+
+use crate::{
+    file_format::{self, number},
+    game_mode::GameMode,
+    rules::RulesPreset,
+    run_stats::RunSummary,
+    storage,
+};
+
+/// File the best runs are saved to
+pub const HIGHSCORES_FILE: &str = "highscores.txt";
+
+/// Runs kept for each game mode
+pub const MAX_HIGHSCORES: usize = 10;
+
+/// A finished run and its key stats
+#[derive(Clone, Debug)]
+pub struct HighScore {
+    pub mode: GameMode,
+    pub rules: RulesPreset,
+    /// "2024-03-09", the day the run ended
+    pub date: String,
+    pub seed: u64,
+    pub score: u32,
+    pub days: u32,
+    pub earned: f32,
+    pub peak_headcount: usize,
+    /// Employees who died, fled, jumped or burned
+    pub lost: usize,
+}
+
+impl HighScore {
+    pub fn new(mode: GameMode, rules: RulesPreset, seed: u64, summary: &RunSummary) -> Self {
+        Self {
+            mode,
+            rules,
+            date: today(),
+            seed,
+            score: summary.get_score(),
+            days: summary.days,
+            earned: summary.earned,
+            peak_headcount: summary.peak_headcount,
+            lost: summary.died + summary.fled + summary.jumped + summary.burned,
+        }
+    }
+}
+
+/// Date of today, from the days since 1970 (Howard Hinnant's `civil_from_days`)
+fn today() -> String {
+    let days = (date::now() / 86400.).floor() as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// The best runs of each game mode, best first
+#[derive(Default)]
+pub struct HighScores {
+    runs: Vec<HighScore>,
+}
+
+impl HighScores {
+    /// Runs saved in `HIGHSCORES_FILE`, or none if it can not be read. Lines
+    /// that can not be read are skipped, so that the next save keeps the others.
+    pub fn load() -> Self {
+        let Some(text) = storage::read(HIGHSCORES_FILE) else {
+            return Self::default();
+        };
+
+        let (scores, errors) = Self::parse(&text);
+        for error in errors {
+            println!("{HIGHSCORES_FILE} : {error}, ignorée");
+        }
+
+        scores
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        storage::write(HIGHSCORES_FILE, &self.to_text())
+    }
+
+    /// The runs of the text, and the errors of the lines skipped
+    pub fn parse(text: &str) -> (Self, Vec<String>) {
+        let mut scores = Self::default();
+
+        let errors = file_format::parse_lines_skipping(text, |words| {
+            let ["run", mode, rules, date, seed, score, days, earned, peak, lost] = words else {
+                return Err(file_format::unknown_line());
+            };
+
+            scores.runs.push(HighScore {
+                mode: GameMode::from_key(mode).ok_or_else(|| format!("mode inconnu \"{mode}\""))?,
+                rules: RulesPreset::from_key(rules)
+                    .ok_or_else(|| format!("difficulté inconnue \"{rules}\""))?,
+                date: (*date).to_owned(),
+                seed: number(seed)?,
+                score: number(score)?,
+                days: number(days)?,
+                earned: number::<u64>(earned)? as f32,
+                peak_headcount: number(peak)?,
+                lost: number(lost)?,
+            });
+
+            Ok(())
+        });
+
+        scores.runs.sort_by_key(|r| Reverse(r.score));
+        (scores, errors)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from(
+            "# run <mode> <difficulté> <date> <graine> <score> <jours> <argent> <effectif> <pertes>\n",
+        );
+
+        for run in &self.runs {
+            writeln!(
+                text,
+                "run {} {} {} {} {} {} {} {} {}",
+                run.mode.get_key(),
+                run.rules.get_key(),
+                run.date,
+                run.seed,
+                run.score,
+                run.days,
+                run.earned.max(0.).round(),
+                run.peak_headcount,
+                run.lost
+            )
+            .unwrap();
+        }
+
+        text
+    }
+
+    /// Adds the run if it is among the best of its mode, and returns its rank
+    /// from 0
+    pub fn add(&mut self, run: HighScore) -> Option<usize> {
+        let mode = run.mode;
+        let rank = self.get_best(mode).filter(|r| r.score >= run.score).count();
+
+        if rank >= MAX_HIGHSCORES {
+            return None;
+        }
+
+        let index = self
+            .runs
+            .iter()
+            .position(|r| r.score < run.score)
+            .unwrap_or(self.runs.len());
+        self.runs.insert(index, run);
+
+        // Forgets the runs of the mode that are not among the best anymore
+        let mut kept = 0;
+        self.runs.retain(|r| {
+            if r.mode != mode {
+                return true;
+            }
+            kept += 1;
+            kept <= MAX_HIGHSCORES
+        });
+
+        Some(rank)
+    }
+
+    pub fn get_best(&self, mode: GameMode) -> impl Iterator<Item = &HighScore> {
+        self.runs.iter().filter(move |r| r.mode == mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(mode: GameMode, score: u32) -> HighScore {
+        HighScore {
+            mode,
+            rules: RulesPreset::Normal,
+            date: "2024-03-09".to_owned(),
+            seed: 42,
+            score,
+            days: 3,
+            earned: 1500.,
+            peak_headcount: 5,
+            lost: 1,
+        }
+    }
+
+    fn scores(scores: &HighScores, mode: GameMode) -> Vec<u32> {
+        scores.get_best(mode).map(|r| r.score).collect()
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let mut high_scores = HighScores::default();
+        high_scores.add(run(GameMode::MyLittleOffice, 300));
+        high_scores.add(HighScore {
+            rules: RulesPreset::Crunch,
+            ..run(GameMode::Endless, 1200)
+        });
+
+        let text = high_scores.to_text();
+        let (parsed, errors) = HighScores::parse(&text);
+
+        assert!(errors.is_empty());
+
+        assert_eq!(parsed.to_text(), text);
+        assert_eq!(
+            parsed.get_best(GameMode::Endless).next().unwrap().rules,
+            RulesPreset::Crunch
+        );
+    }
+
+    #[test]
+    fn parse_sorts_the_runs() {
+        let text = "run office normal 2024-03-09 1 100 1 0 1 0\n\
+                    run office hard 2024-03-10 2 500 5 0 3 0\n";
+        let (high_scores, _) = HighScores::parse(text);
+
+        assert_eq!(
+            scores(&high_scores, GameMode::MyLittleOffice),
+            vec![500, 100]
+        );
+    }
+
+    #[test]
+    fn bad_lines_are_skipped() {
+        let text = "run office 2024-03-09 1 100 1 0 1 0\n\
+                    run office legendary 2024-03-09 1 200 1 0 1 0\n\
+                    run office hard 2024-03-10 2 300 5 0 3 0\n";
+        let (high_scores, errors) = HighScores::parse(text);
+
+        assert_eq!(scores(&high_scores, GameMode::MyLittleOffice), vec![300]);
+        assert_eq!(
+            errors,
+            vec![
+                "ligne 1 : ligne incomprise".to_owned(),
+                "ligne 2 : difficulté inconnue \"legendary\"".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn ties_rank_after_the_older_runs() {
+        let mut high_scores = HighScores::default();
+
+        assert_eq!(high_scores.add(run(GameMode::MyLittleOffice, 100)), Some(0));
+        assert_eq!(high_scores.add(run(GameMode::MyLittleOffice, 100)), Some(1));
+        assert_eq!(high_scores.add(run(GameMode::MyLittleOffice, 150)), Some(0));
+        assert_eq!(
+            scores(&high_scores, GameMode::MyLittleOffice),
+            vec![150, 100, 100]
+        );
+    }
+
+    #[test]
+    fn keeps_the_best_runs_of_each_mode() {
+        let mut high_scores = HighScores::default();
+        high_scores.add(run(GameMode::CrunchSimulator, 10));
+
+        for _ in 0..MAX_HIGHSCORES {
+            assert!(high_scores
+                .add(run(GameMode::MyLittleOffice, 100))
+                .is_some());
+        }
+
+        // A full table only takes better runs
+        assert_eq!(high_scores.add(run(GameMode::MyLittleOffice, 100)), None);
+        assert_eq!(high_scores.add(run(GameMode::MyLittleOffice, 200)), Some(0));
+
+        let best = scores(&high_scores, GameMode::MyLittleOffice);
+        assert_eq!(best.len(), MAX_HIGHSCORES);
+        assert_eq!(best[0], 200);
+
+        // The other modes have their own table
+        assert_eq!(scores(&high_scores, GameMode::CrunchSimulator), vec![10]);
+        assert_eq!(high_scores.add(run(GameMode::CrunchSimulator, 5)), Some(1));
+    }
+}
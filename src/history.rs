@@ -0,0 +1,94 @@
+use crate::employee::{EmployeeAction, Stat};
+
+#[derive(Clone, Debug)]
+pub enum HistoryEvent {
+    Hired,
+    Action(EmployeeAction),
+    BrokeComputer,
+    BrokeDoor,
+    /// Found the window closed when trying to jump
+    BrokeDown,
+    /// Moved to another floor of the company
+    Transferred,
+    /// Left at evening with the door open
+    WentHome,
+    /// A QTE answer or a bonus that affected the employee
+    Qte(String),
+    /// A stat reached its minimum (false) or maximum (true)
+    StatExtreme(Stat, bool),
+    Died,
+    Fled,
+    Jumped,
+    Burned,
+}
+
+impl HistoryEvent {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Hired => "Embauché".to_owned(),
+            Self::Action(action) => match action {
+                EmployeeAction::None => "Retourne au travail".to_owned(),
+                EmployeeAction::Break => "Prend une pause".to_owned(),
+                EmployeeAction::Eat => "Mange".to_owned(),
+                EmployeeAction::Sleep => "Dort".to_owned(),
+                EmployeeAction::FamilyCall => "Appelle sa famille".to_owned(),
+                EmployeeAction::ForcedSleep => "S'effondre de fatigue".to_owned(),
+            },
+            Self::BrokeComputer => "Casse son ordinateur".to_owned(),
+            Self::BrokeDoor => "Enfonce la porte".to_owned(),
+            Self::BrokeDown => "S'effondre devant la fenêtre fermée".to_owned(),
+            Self::Transferred => "Muté à un autre étage".to_owned(),
+            Self::WentHome => "Rentre à la maison".to_owned(),
+            Self::Qte(text) => text.clone(),
+            Self::StatExtreme(stat, high) => format!(
+                "{} au {}",
+                stat.get_name(),
+                if *high { "maximum" } else { "minimum" }
+            ),
+            Self::Died => "Mort".to_owned(),
+            Self::Fled => "S'est enfui".to_owned(),
+            Self::Jumped => "S'est défenestré".to_owned(),
+            Self::Burned => "A péri dans les flammes".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub day: u32,
+    pub event: HistoryEvent,
+}
+
+impl HistoryEntry {
+    pub fn describe(&self) -> String {
+        format!("Jour {} : {}", self.day, self.event.describe())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fate {
+    Died,
+    Fled,
+    Jumped,
+    Burned,
+}
+
+impl Fate {
+    pub const fn describe(self) -> &'static str {
+        match self {
+            Self::Died => "Mort",
+            Self::Fled => "Enfui",
+            Self::Jumped => "Défenestré",
+            Self::Burned => "Brûlé",
+        }
+    }
+}
+
+/// Obituary of an employee who is no longer part of the office
+#[derive(Clone, Debug)]
+pub struct MemorialEntry {
+    pub name: String,
+    pub fate: Fate,
+    pub day: u32,
+    pub history: Vec<HistoryEntry>,
+}
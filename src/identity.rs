@@ -0,0 +1,186 @@
+use macroquad::{color::Color, rand::gen_range};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Locale {
+    French,
+    Italian,
+    English,
+    Dutch,
+}
+
+impl Locale {
+    pub const fn country(self) -> &'static str {
+        match self {
+            Self::French => "France",
+            Self::Italian => "Italie",
+            Self::English => "Angleterre",
+            Self::Dutch => "Pays-Bas",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Gender {
+    Female,
+    Male,
+    NonBinary,
+}
+
+impl Gender {
+    pub const fn pronoun(self) -> &'static str {
+        match self {
+            Self::Female => "elle",
+            Self::Male => "il",
+            Self::NonBinary => "iel",
+        }
+    }
+}
+
+struct NamePool {
+    female: &'static [&'static str],
+    male: &'static [&'static str],
+    last: &'static [&'static str],
+}
+
+const FRENCH_NAMES: NamePool = NamePool {
+    female: &[
+        "Cynthia", "Valérie", "Sophie", "Camille", "Chloé", "Manon", "Juliette", "Nathalie",
+    ],
+    male: &[
+        "Baptiste", "Yannis", "Valentin", "Alain", "Yves", "Cédric", "Olivier", "Mathieu",
+        "Roland", "Tom",
+    ],
+    last: &[
+        "Martin", "Bernard", "Dubois", "Thomas", "Robert", "Richard", "Petit", "Durand", "Leroy",
+        "Moreau", "Fournier", "Girard",
+    ],
+};
+
+const ITALIAN_NAMES: NamePool = NamePool {
+    female: &["Giulia", "Francesca", "Chiara", "Sofia", "Alessia"],
+    male: &["Marcello", "Stephano", "Luca", "Giovanni", "Matteo"],
+    last: &["Rossi", "Russo", "Ferrari", "Esposito", "Bianchi", "Romano"],
+};
+
+const ENGLISH_NAMES: NamePool = NamePool {
+    female: &["Emily", "Olivia", "Grace", "Alice"],
+    male: &["Jack", "Harry", "Oliver", "George"],
+    last: &["Smith", "Jones", "Taylor", "Brown", "Wilson"],
+};
+
+const DUTCH_NAMES: NamePool = NamePool {
+    female: &["Anouk", "Sanne", "Fenna"],
+    male: &["Edsger", "Daan", "Bram", "Sem"],
+    last: &["de Vries", "Jansen", "Bakker", "Visser", "Dijkstra"],
+};
+
+const SKIN_TONES: &[Color] = &[
+    Color::new(0.98, 0.87, 0.74, 1.),
+    Color::new(0.85, 0.74, 0.55, 1.),
+    Color::new(0.76, 0.58, 0.40, 1.),
+    Color::new(0.58, 0.40, 0.26, 1.),
+    Color::new(0.38, 0.25, 0.16, 1.),
+];
+
+const HAIR_COLORS: &[Color] = &[
+    Color::new(0.35, 0.15, 0.0, 1.),
+    Color::new(0.08, 0.06, 0.05, 1.),
+    Color::new(0.85, 0.70, 0.35, 1.),
+    Color::new(0.65, 0.25, 0.08, 1.),
+    Color::new(0.70, 0.70, 0.70, 1.),
+];
+
+const SHIRT_COLORS: &[Color] = &[
+    Color::new(0.88, 0.45, 0.43, 1.),
+    Color::new(0.40, 0.55, 0.85, 1.),
+    Color::new(0.45, 0.75, 0.45, 1.),
+    Color::new(0.90, 0.80, 0.35, 1.),
+    Color::new(0.60, 0.45, 0.80, 1.),
+    Color::new(0.45, 0.45, 0.45, 1.),
+    Color::new(0.95, 0.95, 0.95, 1.),
+];
+
+fn pick<T: Copy>(list: &[T]) -> T {
+    list[gen_range(0, list.len())]
+}
+
+/// Who an employee is and how they look in the office
+#[derive(Clone, Debug)]
+pub struct Identity {
+    pub first_name: &'static str,
+    pub last_name: &'static str,
+    pub gender: Gender,
+    pub locale: Locale,
+    pub age: u32,
+    pub skin_tone: Color,
+    pub hair_color: Color,
+    pub shirt_color: Color,
+    pub bald: bool,
+}
+
+impl Identity {
+    pub fn random() -> Self {
+        let locale = pick(&[
+            Locale::French,
+            Locale::French,
+            Locale::French,
+            Locale::Italian,
+            Locale::English,
+            Locale::Dutch,
+        ]);
+        let pool = match locale {
+            Locale::French => &FRENCH_NAMES,
+            Locale::Italian => &ITALIAN_NAMES,
+            Locale::English => &ENGLISH_NAMES,
+            Locale::Dutch => &DUTCH_NAMES,
+        };
+
+        let gender = match gen_range(0, 20) {
+            0 => Gender::NonBinary,
+            1..=9 => Gender::Female,
+            _ => Gender::Male,
+        };
+
+        let first_name = match gender {
+            Gender::Female => pick(pool.female),
+            Gender::Male => pick(pool.male),
+            Gender::NonBinary => {
+                if gen_range(0, 2) == 0 {
+                    pick(pool.female)
+                } else {
+                    pick(pool.male)
+                }
+            }
+        };
+
+        Self {
+            first_name,
+            last_name: pick(pool.last),
+            gender,
+            locale,
+            age: gen_range(18, 65),
+            skin_tone: pick(SKIN_TONES),
+            hair_color: pick(HAIR_COLORS),
+            shirt_color: pick(SHIRT_COLORS),
+            bald: gen_range(0, 8) == 0,
+        }
+    }
+
+    /// Draws identities until one has a name that is not already taken
+    pub fn random_unique(taken: &[String]) -> Self {
+        let mut identity = Self::random();
+
+        for _ in 0..20 {
+            if !taken.contains(&identity.full_name()) {
+                break;
+            }
+            identity = Self::random();
+        }
+
+        identity
+    }
+
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+}
@@ -0,0 +1,437 @@
+use std::{f32::consts::PI, fmt::Write};
+
+use macroquad::math::{Rect, Vec2};
+
+use crate::{
+    employee::Computer,
+    file_format::{self, finite},
+    navigation::NavGrid,
+    storage,
+    zone::{Zone, ZoneKind},
+};
+
+/// File the layout is saved to
+pub const LAYOUT_FILE: &str = "layout.txt";
+
+/// Furniture snaps to this grid
+pub const GRID_SIZE: f32 = 10.;
+
+/// Part of the office where furniture can be placed
+pub const ROOM: Rect = Rect::new(355., 80., 685., 560.);
+
+pub const DESK_SIZE: Vec2 = Vec2::new(130., 155.);
+
+/// Right behind the door, every seat must be reachable from there
+const DOOR_INSIDE: Vec2 = Vec2::new(370., 360.);
+
+/// Distance between the side of a desk and the seats next to it
+const SEAT_DISTANCE: f32 = 35.;
+/// Height of the two seats of each side, from the top of the desk
+const SEAT_Y: [f32; 2] = [25., 115.];
+/// Computers are on the desk, in front of their seat
+const COMPUTER_DISTANCE: f32 = 60.;
+
+/// A desk and the computers on its four seats, left ones first
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeskPlacement {
+    pub pos: Vec2,
+    pub computers: [bool; 4],
+}
+
+impl DeskPlacement {
+    pub const fn new(pos: Vec2) -> Self {
+        Self {
+            pos,
+            computers: [true; 4],
+        }
+    }
+
+    pub const fn get_rect(&self) -> Rect {
+        Rect::new(self.pos.x, self.pos.y, DESK_SIZE.x, DESK_SIZE.y)
+    }
+
+    /// Where the employee of the seat sits
+    pub fn seat(&self, seat: usize) -> Vec2 {
+        let x = if seat < 2 {
+            self.pos.x - SEAT_DISTANCE
+        } else {
+            self.pos.x + DESK_SIZE.x + SEAT_DISTANCE
+        };
+
+        Vec2::new(x, self.pos.y + SEAT_Y[seat % 2])
+    }
+
+    /// Where the computer of the seat is, and its rotation
+    pub fn computer(&self, seat: usize) -> (Vec2, f32) {
+        let seat_pos = self.seat(seat);
+
+        if seat < 2 {
+            (seat_pos + Vec2::new(COMPUTER_DISTANCE, 0.), PI / 2.)
+        } else {
+            (seat_pos - Vec2::new(COMPUTER_DISTANCE, 0.), -PI / 2.)
+        }
+    }
+}
+
+/// An activity zone, placed by its top left corner
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZonePlacement {
+    pub kind: ZoneKind,
+    pub pos: Vec2,
+}
+
+impl ZonePlacement {
+    pub const fn get_rect(&self) -> Rect {
+        let size = match self.kind {
+            ZoneKind::Kitchen => Vec2::new(140., 60.),
+            ZoneKind::BreakRoom => Vec2::new(120., 60.),
+            ZoneKind::SleepingPods => Vec2::new(140., 50.),
+            ZoneKind::PhoneBooth => Vec2::new(110., 60.),
+        };
+
+        Rect::new(self.pos.x, self.pos.y, size.x, size.y)
+    }
+
+    pub fn slots(&self) -> Vec<Vec2> {
+        let rect = self.get_rect();
+        let y = rect.y + rect.h / 2.;
+
+        match self.kind {
+            ZoneKind::PhoneBooth => vec![Vec2::new(rect.x + rect.w / 2., y)],
+            _ => vec![
+                Vec2::new(rect.x + rect.w / 4., y),
+                Vec2::new(rect.x + rect.w * 3. / 4., y),
+            ],
+        }
+    }
+
+    /// The queue goes toward the middle of the room
+    pub fn to_zone(self) -> Zone {
+        let rect = self.get_rect();
+        let x = rect.x + rect.w / 2.;
+
+        let (queue_start, queue_step) = if rect.center().y < ROOM.center().y {
+            (Vec2::new(x, rect.bottom() + 30.), Vec2::new(0., 45.))
+        } else {
+            (Vec2::new(x, rect.y - 35.), Vec2::new(0., -45.))
+        };
+
+        Zone::new(self.kind, rect, self.slots(), queue_start, queue_step)
+    }
+}
+
+/// Placement of the desks, computers and activity zones of the office
+#[derive(Clone, Debug, PartialEq)]
+pub struct Layout {
+    pub desks: Vec<DeskPlacement>,
+    pub zones: Vec<ZonePlacement>,
+}
+
+impl Default for Layout {
+    /// The desks of the original office drawing, with four computers each.
+    /// Seats are placed the same way around every desk, so the right and
+    /// lower ones are a few pixels off the hand-placed spots of the original.
+    fn default() -> Self {
+        Self {
+            desks: [(485., 150.), (780., 150.), (485., 430.), (780., 430.)]
+                .iter()
+                .map(|&(x, y)| DeskPlacement::new(Vec2::new(x, y)))
+                .collect(),
+            zones: [
+                (ZoneKind::Kitchen, 630., 80.),
+                (ZoneKind::SleepingPods, 630., 590.),
+                (ZoneKind::PhoneBooth, 365., 80.),
+                (ZoneKind::BreakRoom, 915., 80.),
+            ]
+            .iter()
+            .map(|&(kind, x, y)| ZonePlacement {
+                kind,
+                pos: Vec2::new(x, y),
+            })
+            .collect(),
+        }
+    }
+}
+
+impl ZoneKind {
+    const fn get_key(self) -> &'static str {
+        match self {
+            Self::Kitchen => "kitchen",
+            Self::BreakRoom => "break",
+            Self::SleepingPods => "pods",
+            Self::PhoneBooth => "phone",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.get_key() == key)
+    }
+}
+
+/// Rounds the position to the grid
+pub fn snap(pos: Vec2) -> Vec2 {
+    ((pos - ROOM.point()) / GRID_SIZE).round() * GRID_SIZE + ROOM.point()
+}
+
+impl Layout {
+    /// Layout saved in `LAYOUT_FILE`, or the default one if there is none or
+    /// if it is not valid
+    pub fn load() -> Self {
+        let Some(text) = storage::read(LAYOUT_FILE) else {
+            return Self::default();
+        };
+
+        match Self::parse(&text).and_then(|layout| layout.validate().map(|()| layout)) {
+            Ok(layout) => layout,
+            Err(error) => {
+                println!("{LAYOUT_FILE} ignoré : {error}");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        self.validate()?;
+
+        storage::write(LAYOUT_FILE, &self.to_text())
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut layout = Self {
+            desks: Vec::new(),
+            zones: Vec::new(),
+        };
+
+        file_format::parse_lines(text, |words| {
+            match words {
+                ["desk", x, y, computers] => {
+                    if computers.len() != 4 || computers.chars().any(|c| c != '0' && c != '1') {
+                        return Err("les ordinateurs s'écrivent avec quatre 0 ou 1".to_owned());
+                    }
+
+                    let mut desk = DeskPlacement::new(Vec2::new(finite(x)?, finite(y)?));
+                    computers
+                        .chars()
+                        .enumerate()
+                        .for_each(|(i, c)| desk.computers[i] = c == '1');
+                    layout.desks.push(desk);
+                }
+                ["zone", kind, x, y] => {
+                    let kind = ZoneKind::from_key(kind)
+                        .ok_or_else(|| format!("zone inconnue \"{kind}\""))?;
+                    layout.zones.push(ZonePlacement {
+                        kind,
+                        pos: Vec2::new(finite(x)?, finite(y)?),
+                    });
+                }
+                _ => return Err(file_format::unknown_line()),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(layout)
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from(
+            "# desk <x> <y> <ordinateurs>\n# zone <kitchen|break|pods|phone> <x> <y>\n",
+        );
+
+        for desk in &self.desks {
+            let computers = desk
+                .computers
+                .iter()
+                .map(|&c| if c { '1' } else { '0' })
+                .collect::<String>();
+            writeln!(text, "desk {} {} {}", desk.pos.x, desk.pos.y, computers).unwrap();
+        }
+
+        for zone in &self.zones {
+            writeln!(
+                text,
+                "zone {} {} {}",
+                zone.kind.get_key(),
+                zone.pos.x,
+                zone.pos.y
+            )
+            .unwrap();
+        }
+
+        text
+    }
+
+    /// Checks that there is one zone of each kind, that the furniture fits in
+    /// the room without overlapping, and that every seat and zone slot can be
+    /// reached from the door
+    pub fn validate(&self) -> Result<(), String> {
+        for kind in ZoneKind::ALL {
+            match self.zones.iter().filter(|z| z.kind == kind).count() {
+                0 => return Err(format!("Il manque : {}.", kind.get_name().to_lowercase())),
+                1 => (),
+                _ => {
+                    return Err(format!(
+                        "Il y a plusieurs fois : {}.",
+                        kind.get_name().to_lowercase()
+                    ))
+                }
+            }
+        }
+
+        let rects = self
+            .desks
+            .iter()
+            .map(|d| ("Un bureau", d.get_rect()))
+            .chain(self.zones.iter().map(|z| (z.kind.get_name(), z.get_rect())))
+            .collect::<Vec<(&str, Rect)>>();
+
+        for (i, (name, rect)) in rects.iter().enumerate() {
+            if rect.x < ROOM.x
+                || rect.y < ROOM.y
+                || rect.right() > ROOM.right()
+                || rect.bottom() > ROOM.bottom()
+            {
+                return Err(format!("{name} dépasse de la pièce."));
+            }
+
+            if let Some((other, _)) = rects[i + 1..].iter().find(|(_, r)| r.overlaps(rect)) {
+                return Err(format!("{} chevauche : {}.", name, other.to_lowercase()));
+            }
+        }
+
+        if !self.desks.iter().any(|d| d.computers.contains(&true)) {
+            return Err("Il faut au moins un ordinateur.".to_owned());
+        }
+
+        let nav = NavGrid::for_layout(self);
+        let reachable =
+            |pos: Vec2| nav.is_walkable(pos) && nav.find_path(DOOR_INSIDE, pos).is_some();
+
+        for desk in &self.desks {
+            if (0..4).any(|seat| desk.computers[seat] && !reachable(desk.seat(seat))) {
+                return Err("Une place n'est pas accessible depuis la porte.".to_owned());
+            }
+        }
+
+        for zone in &self.zones {
+            if !zone.slots().into_iter().all(reachable) {
+                return Err(format!(
+                    "{} n'est pas accessible depuis la porte.",
+                    zone.kind.get_name()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_desk_rects(&self) -> Vec<Rect> {
+        self.desks.iter().map(DeskPlacement::get_rect).collect()
+    }
+
+    /// One computer for each seat that has one
+    pub fn build_computers(&self) -> Vec<Computer> {
+        self.desks
+            .iter()
+            .flat_map(|desk| {
+                (0..4).filter(|&seat| desk.computers[seat]).map(|seat| {
+                    let (position, rotation) = desk.computer(seat);
+                    Computer::new(position, desk.seat(seat), rotation)
+                })
+            })
+            .collect()
+    }
+
+    pub fn build_zones(&self) -> Vec<Zone> {
+        self.zones.iter().map(|z| z.to_zone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_layout_is_valid() {
+        assert_eq!(Layout::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let mut layout = Layout::default();
+        layout.desks[1].computers = [true, false, false, true];
+        layout.zones[0].pos = Vec2::new(640., 85.5);
+
+        assert_eq!(Layout::parse(&layout.to_text()), Ok(layout));
+    }
+
+    #[test]
+    fn malformed_furniture_is_an_error() {
+        assert_eq!(
+            Layout::parse("desk 485 150 1111\nzone garage 0 0"),
+            Err("ligne 2 : zone inconnue \"garage\"".to_owned())
+        );
+        assert!(Layout::parse("desk 485 150 11").is_err());
+        assert!(Layout::parse("desk 485 150").is_err());
+    }
+
+    #[test]
+    fn overlapping_furniture_is_invalid() {
+        let mut layout = Layout::default();
+        layout.desks[1].pos = layout.desks[0].pos + Vec2::new(50., 0.);
+
+        assert!(layout.validate().unwrap_err().contains("chevauche"));
+    }
+
+    #[test]
+    fn furniture_out_of_the_room_is_invalid() {
+        let mut layout = Layout::default();
+        layout.desks[0].pos.y = ROOM.bottom() - 10.;
+
+        assert!(layout.validate().unwrap_err().contains("dépasse"));
+    }
+
+    #[test]
+    fn unreachable_seat_is_invalid() {
+        // The left seats are in the wall
+        let mut layout = Layout::default();
+        layout.desks[0].pos.x = ROOM.x;
+
+        assert_eq!(
+            layout.validate(),
+            Err("Une place n'est pas accessible depuis la porte.".to_owned())
+        );
+
+        // Without their computers, they do not need to be reached
+        layout.desks[0].computers = [false, false, true, true];
+        assert_eq!(layout.validate(), Ok(()));
+    }
+
+    #[test]
+    fn every_zone_kind_is_needed_once() {
+        let mut layout = Layout::default();
+        layout.zones.retain(|z| z.kind != ZoneKind::SleepingPods);
+
+        assert_eq!(layout.validate(), Err("Il manque : dortoir.".to_owned()));
+
+        let mut layout = Layout::default();
+        let mut kitchen = layout.zones[0];
+        kitchen.pos = Vec2::new(630., 330.);
+        layout.zones.push(kitchen);
+
+        assert!(layout.validate().unwrap_err().contains("plusieurs"));
+    }
+
+    #[test]
+    fn at_least_one_computer_is_needed() {
+        let mut layout = Layout::default();
+        for desk in &mut layout.desks {
+            desk.computers = [false; 4];
+        }
+
+        assert_eq!(
+            layout.validate(),
+            Err("Il faut au moins un ordinateur.".to_owned())
+        );
+    }
+}
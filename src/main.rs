@@ -5,36 +5,74 @@
     clippy::cast_possible_truncation
 )]
 
+mod achievements;
 mod assets;
+mod calendar;
+mod campaign;
+mod company;
 mod drawing;
+mod editor;
 mod employee;
+mod file_format;
+mod fire;
+mod game_mode;
+mod highscores;
+mod history;
+mod identity;
+mod layout;
+mod navigation;
+mod personality;
+mod policy;
 mod qte;
+mod relationship;
+mod rules;
+mod run_stats;
+mod screen_layout;
+mod storage;
+mod technician;
+mod zone;
 
 use std::{cell::RefCell, f32::consts::PI, rc::Rc};
 
-use drawing::Drawing;
-use employee::{EmployeeAction, Office, BONUS_METH_COST, BONUS_RH_COST};
+use achievements::{
+    Achievement, Achievements, ALWAYS_NO_ANSWERS, FULL_OFFICE_HEADCOUNT, OPEN_DOOR_DAYS, SOBER_DAYS,
+};
+
+use calendar::{Holiday, Season};
+use campaign::{Campaign, LevelRun, Tool, LEVELS};
+use company::Company;
+use drawing::{Drawing, PolicyButton};
+use editor::{EditorEvent, LayoutEditor};
+use employee::{DoorState, EmployeeAction, EmployeeState, Office, SelectionFilter, WindowState};
+use game_mode::GameMode;
+use highscores::{HighScore, HighScores};
+use history::{Fate, HistoryEvent};
 use macroquad::{experimental::coroutines::wait_seconds, prelude::*};
-use qte::{QteEffect, QTE};
-
-const MIN_PERIOD_WITHOUT_QTE: f32 = 10.;
-const MAX_PERIOD_WITHOUT_QTE: f32 = 20.;
+use policy::TEMPLATES;
+use qte::{QteEffect, QteKind, QteMoment, QTE};
+use rules::Rules;
+use run_stats::{RunStats, RunSummary};
+use screen_layout::ScreenLayout;
 
 const DISPLAY_ANSWER_TIME: f32 = 5.;
 
 pub const DOOR_CD: f64 = 0.5;
-pub const RH_CD: f64 = 3.;
-pub const METH_CD: f64 = 5.;
 const NIGHT_SPEED: f32 = 0.001;
+/// Brightness of the office in the middle of the night
+const NIGHT_MIN: f32 = 0.45;
 
 const DAY_TIME: f64 = 5.;
 
+/// Smaller drags in the office are clicks, not box selections
+const MIN_SELECTION_BOX: f32 = 10.;
+
 #[derive(Clone, Copy)]
 pub enum GameState {
     Running,
     GameOver,
     MyLittleOfficeMenu,
     CrunchSimulatorMenu,
+    LayoutEditor,
 }
 
 enum Day {
@@ -46,7 +84,7 @@ enum Day {
 
 struct Game {
     drawing: Rc<RefCell<Drawing>>,
-    office: Office,
+    company: Company,
     qte_ongoing: Option<QTE>,
     starting_time_qte: f32,
     waiting_time_qte: f32,
@@ -59,17 +97,36 @@ struct Game {
     rh_start_cd: f64,
     meth_start_cd: f64,
     menu: Rc<RefCell<Menu>>,
+    editor: Option<LayoutEditor>,
     night_value: f32,
     day: Day,
     start_timer_day: f64,
+    /// The QTE of the holiday has not been launched yet today
+    holiday_pending: bool,
+    /// Employees burned when the fire alarm went off, until the fires are out
+    burned_at_alarm: Option<usize>,
+    stats: RunStats,
+    mode: GameMode,
+    /// Seed of the random numbers of the run, from the time it started
+    seed: u64,
+    high_scores: HighScores,
+    /// Summary of the finished run, and its rank in the high scores
+    summary: Option<RunSummary>,
+    rank: Option<usize>,
+    achievements: Achievements,
+    campaign: Campaign,
+    /// Campaign level being played, none in free play
+    level: Option<LevelRun>,
+    /// When the last milestone of the endless run was reached
+    milestone_time: Option<f64>,
 }
 
 impl Game {
     pub fn new() -> Self {
         let drawing = Rc::new(RefCell::new(Drawing::new()));
 
-        let mut new_office = Office::new();
-        new_office.add_employee();
+        let mut company = Company::new();
+        company.get_current_mut().add_employee();
         let qtes = vec![
             QTE::new(
                 "C'est l'hiver. Il fait froid. Est-ce que les employés ont le droit à du chauffage ?".to_owned(),
@@ -80,7 +137,8 @@ impl Game {
                 "Après avoir vu votre employé essayé de travaillé avec des moufles, vous comprenez qu'il fait peut-être trop froid. Les employés vous déteste un peu plus".to_owned(),
                 "Le chauffage coûte cher, mais vos employés resteront peut-être plus longtemps.".to_owned(),
                 4.,
-            ),
+            )
+            .with_moment(QteMoment::Season(Season::Winter)),
             QTE::new(
                 "Vos employés ont faim. Faire une pause déjeuner ?".to_owned(),
                 QteEffect::new(0., -0.2, 0.2, 0., 0., 0),
@@ -90,7 +148,8 @@ impl Game {
                 "Vos employés ont pu manger, mais vu la raclette qu'ils ont mangé, ils perdent toute leur énergie".to_owned(),
                 "Vous êtes si prévenant de penser à leur ligne !".to_owned(),
                 4.,
-            ),
+            )
+            .with_key("lunch"),
             QTE::new(
                 "C'est l'été. Il fait chaud. Très chaud. Est-ce que vos employés ont le droit à la clim ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., -200., 0),
@@ -100,7 +159,8 @@ impl Game {
                 "Vos employés sont au frais, pour le moment. Pour votre porte-monnaie par contre, c'est chaud.".to_owned(),
                 "Un employé est mort à cause de la chaleur. Cependant, grâce aux économies, il devrait être possible de faire un nouveau contrat".to_owned(),
                 4.,
-            ),
+            )
+            .with_moment(QteMoment::Season(Season::Summer)),
             QTE::new(
                 "Un stagiaire passe dans le couloir, le capturez ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., 0., 1),
@@ -110,7 +170,8 @@ impl Game {
                 "Après avoir utilisé votre meilleur lasso, le stagiaire fini dans la pièce. A vous de le gérez ! ".to_owned(),
                 "Vous l'avez laissé passer".to_owned(),
                 2.,
-            ),
+            )
+            .with_key("intern"),
             QTE::new(
                 "C'est Nöel, vos employés demande un jour de vancances... Leur accordé ?".to_owned(),
                 QteEffect::new(0.2, 0., 0., 0.4, 0., 0),
@@ -120,7 +181,21 @@ impl Game {
                 "Vos employés sont remplis d'espoir face à cette nouvelle, ils sont également très satisfait.".to_owned(),
                 "Vos employés perdent tout espoir et son mécontent".to_owned(),
                 4.,
-            ),
+            )
+            .with_kind(QteKind::Holiday)
+            .with_moment(QteMoment::Holiday(Holiday::Christmas)),
+            QTE::new(
+                "C'est le nouvel an, vos employés veulent trinquer. Ouvrir le champagne ?".to_owned(),
+                QteEffect::new(0.2, -0.2, 0., 0.1, -150., 0),
+                QteEffect::new(-0.1, 0., 0., -0.2, 0., 0),
+                "Oui".to_owned(),
+                "Non".to_owned(),
+                "Vos employés commencent l'année avec le sourire, et avec la gueule de bois.".to_owned(),
+                "Vos employés fêtent la nouvelle année devant leur écran. Quelle belle année en perspective.".to_owned(),
+                4.,
+            )
+            .with_kind(QteKind::Holiday)
+            .with_moment(QteMoment::Holiday(Holiday::NewYear)),
             QTE::new(
                 "Après 80 semaines intensives, vos employés osent demandé un jour de vacances. Leur accorder ?".to_owned(),
                 QteEffect::new(0., 0.1, 0., 0., -100., 0),
@@ -130,7 +205,8 @@ impl Game {
                 "Vos employé reviennent plus reposé, mais celà à coûté à l'entreprise".to_owned(),
                 "Vos employés sont terriblement fatigués.".to_owned(),
                 4.,
-            ),
+            )
+            .with_moment(QteMoment::Weekend),
             QTE::new(
                 "Un stagiaire demande à vous voir. Une fois dans votre bureau, il se montre... sugestif quand à ses capacités. L'embaucher ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., 0., 1),
@@ -150,7 +226,8 @@ impl Game {
                 "Bien joué ! Des décisions difficile doivent être prise en tant que manager pour la bonne santé de la boite. Vos employés perdent espoir, mais le syndicat ne sera pas au courant. ".to_owned(),
                 "Malheureusement, votre big boss condamne votre inaction et se charge lui même d'élimier la menace. Mais puisque qu'il n'a pas votre expérience dans le métier, le résultat est brouillon et vos employés l'apprennent.".to_owned(),
                 4.,
-            ),
+            )
+            .with_key("union"),
             QTE::new(
                 "Vous suprenez un employé en train de se détendre pendant sa pause en regardant internet. Installer un firewall afin de bloquer tous les sites de distraction ?".to_owned(),
                 QteEffect::new(-0.3, 0.2, 0., 0., -100., 0),
@@ -160,7 +237,8 @@ impl Game {
                 "Vos employés sont très insatisfait, mais leur énergie augmente grâce au sevrage que vous leur imposer. Ah ! et votre porte-monnaie en a pris un coup aussi.".to_owned(),
                 "Vos employés apprécie ce geste de clémence".to_owned(),
                 4.,
-            ),
+            )
+            .with_key("firewall"),
             QTE::new(
                 "L'alarme incendie retenti, voulez-vous évacuer vos employés ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., -1000., 0),
@@ -170,7 +248,8 @@ impl Game {
                 "Vos employés s'en sortent indemne, contrairement à votre porte-monnaie qui subit ce manque à gagner extrême".to_owned(),
                 "Le jeu continu d'être développé et avance parfaitement, bien que quelque perte soient à déplorer.".to_owned(),
                 4.,
-            ),
+            )
+            .with_kind(QteKind::FireAlarm),
             QTE::new(
                 "Votre big boss demande ou en est le jeu. Lui mentir ?".to_owned(),
                 QteEffect::new(-0.1, -0.1, -0.1, -0.1, 0., 0),
@@ -180,7 +259,9 @@ impl Game {
                 "Une fois votre mensonge terminé, vous êtes obligé d'augmenter encore la productivité, et vos employés osent se plaindre".to_owned(),
                 "Une fois la vérité étalé, votre boss vous ordonne de passer le temps de travail journalier de 20h à 21h".to_owned(),
                 4.,
-            ),
+            )
+            .with_key("boss")
+            .with_moment(QteMoment::Weekday),
             QTE::new(
                 "Mail urgent ! Ouvrir maintenant ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., -100., 0),
@@ -190,7 +271,8 @@ impl Game {
                 "Il ne fallait pas l'ouvrir, il s'agissait d'un virus. Vous achetez un nouveau PC".to_owned(),
                 "Bien joué, il s'agissait en réalité d'un virus envoyé par vos employés. Vous décidez donc d'en virez un.".to_owned(),
                 1.5,
-            ),
+            )
+            .with_key("mail"),
             QTE::new(
                 "Un enfant de 10 ans propose de travailler pour vous. Ses compétences vous impréssionne. L'engagez ?".to_owned(),
                 QteEffect::new(-0.3, 0., 0., -0.3, 0., 1),
@@ -200,17 +282,19 @@ impl Game {
                 "Cet enfant est tout à fait compétent, mais les autres employés n'apprécient pas trop de faire travailler un mineur. Il ne sont jamais content".to_owned(),
                 "L'enfant repart en pleurant, faisant perdre à vos autres employé tout espoir en vous.".to_owned(),
                 4.,
-            ),
+            )
+            .with_key("child"),
             QTE::new(
                 "Après de nombreux suicide, vos employés propose de baricader la fenêtre. Les écouter ?".to_owned(),
                 QteEffect::new(0., 0., 0., 0., -300., 0),
                 QteEffect::new(0., 0., 0., -0.3, 0., 0),
                 "Oui".to_owned(),
                 "Non".to_owned(),
-                "Vous payez une entreprise pour baricader cette fenêtre, mais cette dernière ne vient jamais, ayant peur de rester enfermé à jamais.".to_owned(),
+                "Vous payez une entreprise pour baricader cette fenêtre. Plus personne ne sautera, mais vos employés se sentent enfermés à jamais.".to_owned(),
                 "Les employées perdent espoir quand à leur chance de survie.".to_owned(),
                 4.,
-            ),
+            )
+            .with_kind(QteKind::Barricade),
             QTE::new(
                 "Le canitinier vient vous voir annonçant que la nourriture est perimé. L'utiliser quand même ?".to_owned(),
                 QteEffect::new(0., -0.1, 0., 0., 0., 0),
@@ -220,12 +304,60 @@ impl Game {
                 "Vos employés tombent malade, et leur énergie en prend un coup".to_owned(),
                 "Vos employés ont très faim car il ne mange pas".to_owned(),
                 3.,
-            ),
+            )
+            .with_key("food"),
+            QTE::new(
+                "Il est minuit passé et un employé s'est endormi sur son clavier. Le réveiller ?".to_owned(),
+                QteEffect::new(-0.2, -0.2, 0., 0., 150., 0),
+                QteEffect::new(0., 0.2, 0., 0., -100., 0),
+                "Oui".to_owned(),
+                "Non".to_owned(),
+                "Il termine sa fonctionnalité les yeux à moitié fermés. Le client est ravi, vos employés beaucoup moins.".to_owned(),
+                "Il ronfle jusqu'au matin. Le travail attendra, mais pas le client, qui demande un remboursement.".to_owned(),
+                4.,
+            )
+            .with_key("nap")
+            .with_moment(QteMoment::Night),
+            QTE::new(
+                "Vos employés font des heures sup' et réclament des pizzas. Les commander ?".to_owned(),
+                QteEffect::new(0.2, 0., 0.3, 0., -150., 0),
+                QteEffect::new(-0.2, 0., -0.2, 0., 0., 0),
+                "Oui".to_owned(),
+                "Non".to_owned(),
+                "Rien de tel qu'une pizza froide à trois heures du matin pour remonter le moral.".to_owned(),
+                "Vos employés codent le ventre vide et vous maudissent en silence.".to_owned(),
+                4.,
+            )
+            .with_key("pizzas")
+            .with_moment(QteMoment::Night),
+            QTE::new(
+                "Des bruits étranges viennent du couloir. Engager un vigile pour la nuit ?".to_owned(),
+                QteEffect::new(0., 0., 0., 0.1, -200., 0),
+                QteEffect::new(0., 0., 0., -0.2, -400., 0),
+                "Oui".to_owned(),
+                "Non".to_owned(),
+                "Le vigile fait sa ronde, vos employés se sentent un peu plus en sécurité.".to_owned(),
+                "Des cambrioleurs repartent avec du matériel. Vos employés se sentent bien seuls dans le bureau.".to_owned(),
+                4.,
+            )
+            .with_key("guard")
+            .with_moment(QteMoment::Night),
+            QTE::new(
+                "C'est le week-end. Faire venir vos employés quand même ?".to_owned(),
+                QteEffect::new(-0.2, -0.1, 0., -0.3, 400., 0),
+                QteEffect::new(0.2, 0.2, 0., 0.2, -300., 0),
+                "Oui".to_owned(),
+                "Non".to_owned(),
+                "Le travail avance et l'argent rentre, mais vos employés ont oublié à quoi ressemble leur famille.".to_owned(),
+                "Vos employés reviendront reposés lundi, le projet a un peu de retard.".to_owned(),
+                4.,
+            )
+            .with_moment(QteMoment::Weekend),
         ];
 
         Self {
             drawing,
-            office: new_office,
+            company,
             qte_ongoing: None,
             starting_time_qte: 0.,
             waiting_time_qte: 0.,
@@ -238,9 +370,22 @@ impl Game {
             door_start_cd: 0.,
             game_state: GameState::MyLittleOfficeMenu, // TODO initial state should be Game menu
             menu: Rc::new(RefCell::new(Menu::new())),
+            editor: None,
             night_value: 1.,
             day: Day::Day,
             start_timer_day: 0.,
+            holiday_pending: false,
+            burned_at_alarm: None,
+            stats: RunStats::default(),
+            mode: GameMode::MyLittleOffice,
+            seed: 0,
+            high_scores: HighScores::load(),
+            summary: None,
+            rank: None,
+            achievements: Achievements::load(),
+            campaign: Campaign::load(),
+            level: None,
+            milestone_time: None,
         }
     }
 
@@ -251,8 +396,7 @@ impl Game {
         if let Some(qte) = &self.qte_ongoing {
             self.waiting_time_qte = 0.;
             if get_time() as f32 - self.starting_time_qte > qte.get_time() {
-                self.office.apply_qte_effect(qte.get_effect_1());
-                self.quit_qte(qte.get_explication1().to_string());
+                self.answer_qte(&qte.clone(), true);
             }
         } else if let Some(_) = &self.answer {
             if get_time() as f32 - self.starting_time_answer > DISPLAY_ANSWER_TIME {
@@ -262,103 +406,221 @@ impl Game {
             self.waiting_time_qte += 1.0 / FPS;
         }
 
-        if self.waiting_time_qte > self.next_time_qte {
+        if self.qte_ongoing.is_none() && self.company.take_fire_alarm() {
+            self.burned_at_alarm = Some(self.company.fate_count(Fate::Burned));
+            self.qte_ongoing = self.launch_qte_kind(QteKind::FireAlarm);
+        } else if self.qte_ongoing.is_none() && self.answer.is_none() && self.holiday_pending {
+            self.holiday_pending = false;
+            self.qte_ongoing = self.launch_qte_kind(QteKind::Holiday);
+        } else if self.waiting_time_qte > self.next_time_qte {
             self.qte_ongoing = self.launch_qte();
         }
 
-        if is_mouse_button_pressed(MouseButton::Left) {
-            let main_pos =
-                Drawing::convert_screen_main(vec2(mouse_position().0, mouse_position().1));
+        let screen_pos = vec2(mouse_position().0, mouse_position().1);
+        let office_view_pos = Drawing::convert_screen_office_view(screen_pos);
+
+        // Camera of the office: zoom with the wheel and drag with the right button
+        if drawing.get_rect_office().contains(screen_pos) {
+            let wheel = mouse_wheel().1;
+            if wheel != 0. {
+                drawing.zoom_office(wheel, office_view_pos);
+            }
 
-            if drawing.get_rect_office().contains(main_pos) {
-                let pos = Drawing::convert_main_office(main_pos);
-                self.get_mut_office().click(pos);
+            if is_mouse_button_pressed(MouseButton::Right) {
+                drawing.start_office_pan(office_view_pos);
+            }
+        }
+        if is_mouse_button_down(MouseButton::Right) {
+            drawing.pan_office(office_view_pos);
+        } else {
+            drawing.stop_office_pan();
+        }
+
+        // A click selects what is under the mouse, a drag selects a box
+        if is_mouse_button_released(MouseButton::Left) {
+            let pos = drawing.convert_screen_office(screen_pos);
+
+            if let Some(rect) = drawing.stop_selection(pos) {
+                let add = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+
+                if rect.w.max(rect.h) < MIN_SELECTION_BOX {
+                    self.get_mut_office().click(rect.center(), add);
+                } else {
+                    self.get_mut_office().select_in_rect(rect, add);
+                }
                 drawing.reset_displayed();
+            }
+        }
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            if drawing.get_rect_office().contains(screen_pos) {
+                let floors = self.company.floors_count();
 
-                println!("Office pos : {:?}", pos);
-            } else if drawing.get_rect_info().contains(main_pos) {
-                let pos = Drawing::convert_main_info(main_pos);
+                if let Some(tab) =
+                    (0..=floors).find(|&i| Drawing::floor_tab_rect(i).contains(office_view_pos))
+                {
+                    if tab < floors {
+                        self.company.switch_floor(tab);
+                    } else {
+                        self.company.buy_floor();
+                    }
+                } else if Drawing::follow_button_rect().contains(office_view_pos) {
+                    drawing.toggle_follow_selected();
+                } else if let Some(filter) = SelectionFilter::ALL
+                    .into_iter()
+                    .enumerate()
+                    .find(|(i, _)| Drawing::filter_button_rect(*i).contains(office_view_pos))
+                    .map(|(_, filter)| filter)
+                {
+                    self.get_mut_office().select_filter(filter);
+                } else {
+                    let pos = drawing.convert_screen_office(screen_pos);
+                    drawing.start_selection(pos);
+                    println!("Office pos : {pos:?}");
+                }
+                drawing.reset_displayed();
+            } else if drawing.get_rect_info().contains(screen_pos) {
+                let pos = Drawing::convert_screen_info(screen_pos);
                 println!("Info pos : {:?}", pos);
 
                 if let Some(qte) = self.get_qte_ongoing().clone() {
                     if drawing.get_button_choice_1().contains(pos) {
-                        self.get_mut_office().apply_qte_effect(qte.get_effect_1());
-                        self.quit_qte(qte.get_explication1().to_string());
+                        self.answer_qte(&qte, true);
                     } else if drawing.get_button_choice_2().contains(pos) {
-                        self.get_mut_office().apply_qte_effect(qte.get_effect_2());
-                        self.quit_qte(qte.get_explication2().to_string());
+                        self.answer_qte(&qte, false);
+                    }
+                } else if self.get_answer().is_none() && drawing.is_policies_shown() {
+                    let target = Drawing::get_policy_target(self.get_office());
+                    let policies = self.get_mut_office().get_policies_mut();
+
+                    for i in 0..policies.len() {
+                        for button in PolicyButton::ALL {
+                            if Drawing::policy_button_rect(i, button).contains(pos) {
+                                match button {
+                                    PolicyButton::Lower => policies.adjust(i, -1.),
+                                    PolicyButton::Raise => policies.adjust(i, 1.),
+                                    PolicyButton::Toggle => policies.toggle(i),
+                                    PolicyButton::Remove => policies.remove(i),
+                                }
+                                return;
+                            }
+                        }
+                    }
+
+                    if let Some(&(action, trigger)) = TEMPLATES
+                        .iter()
+                        .enumerate()
+                        .find(|(i, _)| Drawing::policy_template_rect(*i).contains(pos))
+                        .map(|(_, template)| template)
+                    {
+                        policies.add(action, trigger, target);
                     }
                 }
-            } else if drawing.get_rect_global_stat().contains(main_pos) {
-                let pos = Drawing::convert_main_global_stat(main_pos);
+            } else if drawing.get_rect_global_stat().contains(screen_pos) {
+                let pos = Drawing::convert_screen_global_stat(screen_pos);
 
                 if drawing.get_button_door().contains(pos) {
-                    if self.door_start_cd == 0. {
+                    if matches!(self.get_office().get_door_state(), DoorState::Broken) {
+                        self.get_mut_office().repair_door();
+                    } else if self.door_start_cd == 0. {
                         self.get_mut_office().update_door();
                         self.door_start_cd = get_time();
+
+                        if matches!(self.get_office().get_door_state(), DoorState::Closed) {
+                            self.stats.record_door_close();
+                        }
                     }
                 } else if drawing.get_button_meth().contains(pos) {
-                    if self.meth_start_cd == 0. && self.office.get_money() >= BONUS_METH_COST {
-                        self.office
-                            .set_money(self.office.get_money() - BONUS_METH_COST);
+                    if !self.is_tool_allowed(Tool::Meth) {
+                        return;
+                    }
+                    let cost = self.company.get_rules().meth_cost;
+
+                    if self.meth_start_cd == 0. && self.company.get_money() >= cost {
+                        let money = self.get_office().get_money();
+                        self.get_mut_office().set_money(money - cost);
 
-                        self.get_mut_office().bonus_meth();
+                        self.company.bonus_meth();
+                        self.stats.record_meth();
+                        self.company
+                            .record_event(&HistoryEvent::Qte("Drogué à la meth".to_owned()));
                         self.meth_start_cd = get_time();
                     }
+                } else if drawing.get_button_memorial().contains(pos) {
+                    drawing.toggle_memorial();
+                } else if drawing.get_button_policies().contains(pos) {
+                    if self.is_tool_allowed(Tool::Policies) {
+                        drawing.toggle_policies();
+                    }
+                } else if drawing.get_button_window().contains(pos) {
+                    if self.is_tool_allowed(Tool::Window) {
+                        self.get_mut_office().update_window();
+                    }
                 } else if drawing.get_button_rh().contains(pos) {
-                    if self.rh_start_cd == 0. && self.office.get_money() >= BONUS_RH_COST {
-                        self.office
-                            .set_money(self.office.get_money() - BONUS_RH_COST);
+                    if !self.is_tool_allowed(Tool::Rh) {
+                        return;
+                    }
+                    let cost = self.company.get_rules().rh_cost;
+
+                    if self.rh_start_cd == 0. && self.company.get_money() >= cost {
+                        let money = self.get_office().get_money();
+                        self.get_mut_office().set_money(money - cost);
                         self.get_mut_office().bonus_rh();
+                        self.stats.record_rh();
                         self.rh_start_cd = get_time();
                     }
                 }
-            } else if drawing.get_rect_personnal_stat().contains(main_pos) {
-                let pos = Drawing::convert_main_personnal_stat(main_pos);
+            } else if drawing.get_rect_personnal_stat().contains(screen_pos) {
+                let pos = Drawing::convert_screen_personnal_stat(screen_pos);
                 println!("personnal pos : {:?}", pos);
 
-                if let Some(employee) = self.get_office().get_selected_employee() {
-                    let mut employee = employee.as_ref().borrow_mut();
-
-                    match employee.get_state() {
-                        employee::EmployeeState::Alive => {
-                            if !matches!(employee.action, EmployeeAction::ForcedSleep) {
-                                if drawing.get_button_energy().contains(pos) {
-                                    if let EmployeeAction::Sleep = employee.action {
-                                        employee.action = EmployeeAction::None;
-                                    } else {
-                                        employee.action = EmployeeAction::Sleep;
-                                    }
-                                } else if drawing.get_button_hope().contains(pos) {
-                                    if let EmployeeAction::FamilyCall = employee.action {
-                                        employee.action = EmployeeAction::None;
-                                    } else {
-                                        employee.action = EmployeeAction::FamilyCall;
-                                    }
-                                } else if drawing.get_button_satiety().contains(pos) {
-                                    if let EmployeeAction::Eat = employee.action {
-                                        employee.action = EmployeeAction::None;
-                                    } else {
-                                        employee.action = EmployeeAction::Eat;
-                                    }
-                                } else if drawing.get_button_satisfaction().contains(pos) {
-                                    if let EmployeeAction::Break = employee.action {
-                                        employee.action = EmployeeAction::None;
-                                    } else {
-                                        employee.action = EmployeeAction::Break;
-                                    }
-                                }
-                            }
+                if let Some(computer) = self.get_office().get_selected_computer().cloned() {
+                    if drawing.get_button_computer_repair().contains(pos) {
+                        self.get_mut_office().repair_computer(&computer);
+                    } else if drawing.get_button_computer_replace().contains(pos) {
+                        self.get_mut_office().replace_computer(&computer);
+                    } else if drawing.get_button_computer_upgrade().contains(pos) {
+                        self.get_mut_office().upgrade_computer(&computer);
+                    } else if drawing.get_button_computer_extinguish().contains(pos) {
+                        self.get_mut_office().extinguish_fire(&computer);
+                    }
+                } else if !self.get_office().get_selected_employees().is_empty() {
+                    if let Some(employee) = self.get_office().get_selected_employee().cloned() {
+                        if self.company.floors_count() > 1
+                            && drawing.get_button_transfer().contains(pos)
+                        {
+                            self.company.transfer_selected_employee();
+                            return;
+                        }
+
+                        if drawing.get_button_history().contains(pos) {
+                            drawing.toggle_history();
+                            return;
                         }
-                        employee::EmployeeState::Dead => {
+
+                        if drawing.is_history_shown() {
+                            return;
+                        }
+
+                        if matches!(employee.borrow().get_state(), EmployeeState::Dead) {
                             if drawing.get_button_satisfaction().contains(pos) {
-                                employee.clean();
+                                employee.borrow_mut().clean();
                             }
+                            return;
                         }
-                        employee::EmployeeState::Falling
-                        | employee::EmployeeState::Clean
-                        | employee::EmployeeState::Suicide
-                        | employee::EmployeeState::Arriving => (),
+                    }
+
+                    // The action buttons apply to the whole selection
+                    if let Some((_, action)) = [
+                        (drawing.get_button_energy(), EmployeeAction::Sleep),
+                        (drawing.get_button_hope(), EmployeeAction::FamilyCall),
+                        (drawing.get_button_satiety(), EmployeeAction::Eat),
+                        (drawing.get_button_satisfaction(), EmployeeAction::Break),
+                    ]
+                    .into_iter()
+                    .find(|(rect, _)| rect.contains(pos))
+                    {
+                        self.get_mut_office().toggle_selected_action(action);
                     }
                 }
             }
@@ -368,11 +630,21 @@ impl Game {
     pub fn tick(&mut self) {
         match self.game_state {
             GameState::Running => {
-                self.office.tick();
+                self.company.set_night(self.is_night());
+                self.company.tick();
+                if self.company.take_door_broken() {
+                    assets::play(&assets::DOOR_BREAK_SOUND);
+                }
+                self.stats.update_headcount(self.company.employees_count());
+                self.check_achievements();
+                self.achievements.update_toasts(get_time());
+                if let Some(level) = &mut self.level {
+                    level.update(&self.company);
+                }
 
                 match self.day {
                     Day::Evening => {
-                        if self.night_value > 1. {
+                        if self.night_value > NIGHT_MIN {
                             self.night_value -= NIGHT_SPEED;
                         } else {
                             self.day = Day::Night;
@@ -384,7 +656,14 @@ impl Game {
                             self.night_value += NIGHT_SPEED;
                         } else {
                             self.day = Day::Day;
-                            self.start_timer_day = get_time()
+                            self.start_timer_day = get_time();
+                            self.company.next_day();
+                            self.stats.record_day(&self.company);
+                            self.stats
+                                .record_dawn_door(self.company.is_any_door_closed());
+                            self.escalate();
+                            self.holiday_pending =
+                                calendar::get_holiday(self.get_office().get_day()).is_some();
                         }
                     }
                     Day::Day => {
@@ -402,10 +681,11 @@ impl Game {
                 if get_time() - self.door_start_cd > DOOR_CD {
                     self.door_start_cd = 0.
                 }
-                if get_time() - self.meth_start_cd > METH_CD {
+                let rules = self.company.get_rules();
+                if get_time() - self.meth_start_cd > rules.meth_cooldown {
                     self.meth_start_cd = 0.
                 }
-                if get_time() - self.rh_start_cd > RH_CD {
+                if get_time() - self.rh_start_cd > rules.rh_cooldown {
                     self.rh_start_cd = 0.
                 }
 
@@ -413,8 +693,10 @@ impl Game {
 
                 self.drawing.clone().borrow_mut().draw(&self);
 
-                if self.office.is_game_over() && self.answer.is_none() {
+                let level_over = self.level.as_ref().is_some_and(LevelRun::is_over);
+                if (self.company.is_game_over() || level_over) && self.answer.is_none() {
                     self.game_state = GameState::GameOver;
+                    self.end_run();
                 }
             }
             GameState::GameOver => {
@@ -425,6 +707,11 @@ impl Game {
 
                 menu.draw(self);
                 menu.tick(self);
+
+                if menu.are_clouds_closed() && is_mouse_button_pressed(MouseButton::Left) {
+                    drop(menu);
+                    self.restart();
+                }
             }
             GameState::MyLittleOfficeMenu => {
                 let menu_clone = self.menu.clone();
@@ -435,12 +722,197 @@ impl Game {
 
                 if menu.game_started {
                     self.game_state = GameState::Running;
+                    self.start_run();
+                } else if matches!(menu.state, MenuState::Start) && is_key_pressed(KeyCode::E) {
+                    self.editor = Some(LayoutEditor::new(self.get_office().get_layout().clone()));
+                    self.game_state = GameState::LayoutEditor;
                 }
             }
             GameState::CrunchSimulatorMenu => (),
+            GameState::LayoutEditor => {
+                let Some(editor) = &mut self.editor else {
+                    return;
+                };
+
+                match editor.tick() {
+                    EditorEvent::Saved => {
+                        self.company.set_layout(editor.get_layout().clone());
+                        self.company.get_current_mut().add_employee();
+                    }
+                    EditorEvent::Quit => {
+                        self.editor = None;
+                        self.game_state = GameState::MyLittleOfficeMenu;
+                        return;
+                    }
+                    EditorEvent::None => (),
+                }
+
+                self.drawing.borrow_mut().draw_layout_editor(editor);
+            }
+        }
+    }
+
+    /// Switches to the next difficulty that can be loaded, before the run starts
+    fn next_rules(&mut self) {
+        let mut preset = self.company.get_rules().preset;
+
+        let rules = loop {
+            preset = preset.next();
+
+            match Rules::load(preset) {
+                Ok(rules) => break rules,
+                Err(error) => println!("Difficulté {} ignorée : {}", preset.get_name(), error),
+            }
+        };
+
+        self.company.set_rules(rules);
+        self.company.get_current_mut().add_employee();
+    }
+
+    /// Back to the title screen for a new run, with the same mode and difficulty
+    fn restart(&mut self) {
+        let mode = self.mode;
+        let preset = self.company.get_rules().preset;
+
+        *self = Self::new();
+        self.mode = mode;
+
+        if let Ok(rules) = Rules::load(preset) {
+            self.company.set_rules(rules);
+            self.company.get_current_mut().add_employee();
+        }
+    }
+
+    /// Sets up the office of the campaign level, before its briefing
+    fn start_level(&mut self, index: usize) {
+        let level = &LEVELS[index];
+        let mut rules = self.company.get_rules().clone();
+        rules.starting_money = level.money;
+
+        self.company.set_layout(level.build_layout());
+        self.company.set_rules(rules);
+        for _ in 0..level.staff {
+            self.get_mut_office().add_employee();
+        }
+
+        self.level = Some(LevelRun::new(index));
+    }
+
+    pub const fn get_level(&self) -> Option<&LevelRun> {
+        self.level.as_ref()
+    }
+
+    /// Every tool can be used outside of the campaign
+    pub fn is_tool_allowed(&self, tool: Tool) -> bool {
+        self.level
+            .as_ref()
+            .is_none_or(|l| l.get_level().allows_tool(tool))
+    }
+
+    /// How much harder the run got, only in endless mode and outside of the
+    /// campaign
+    pub fn get_escalation(&self) -> f32 {
+        if self.level.is_some() {
+            return 1.;
+        }
+
+        self.mode.get_escalation(self.company.get_day())
+    }
+
+    /// The stats decay faster with each new day of an endless run
+    fn escalate(&mut self) {
+        self.company.set_decay_factor(self.get_escalation());
+
+        let day = self.company.get_day();
+        if self.level.is_none() && self.mode.get_milestone(day) != self.mode.get_milestone(day - 1)
+        {
+            self.milestone_time = Some(get_time());
+        }
+    }
+
+    pub const fn get_mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub const fn get_milestone_time(&self) -> Option<f64> {
+        self.milestone_time
+    }
+
+    /// Seeds the random numbers once the intro is over
+    fn start_run(&mut self) {
+        self.seed = macroquad::miniquad::date::now() as u64;
+        rand::srand(self.seed);
+        self.stats.record_day(&self.company);
+
+        if let Some(level) = &mut self.level {
+            level.start(&self.company);
         }
     }
 
+    /// Sums up the run and saves it if it is among the best. Campaign levels
+    /// save their stars instead.
+    fn end_run(&mut self) {
+        let summary = RunSummary::new(&self.stats, &self.company)
+            .with_score_factor(self.mode.get_score_factor(self.company.get_day()));
+
+        if let Some(level) = &mut self.level {
+            let stars = level.finish();
+            self.campaign.record(level.get_index(), stars);
+            self.summary = Some(summary);
+            return;
+        }
+
+        self.rank = self.high_scores.add(HighScore::new(
+            self.mode,
+            self.company.get_rules().preset,
+            self.seed,
+            &summary,
+        ));
+        if self.rank.is_some() {
+            if let Err(error) = self.high_scores.save() {
+                println!("Enregistrement des scores impossible : {error}");
+            }
+        }
+
+        self.summary = Some(summary);
+    }
+
+    /// Unlocks the achievements reached so far in the run
+    fn check_achievements(&mut self) {
+        let day = self.company.get_day();
+
+        // The door closed by the intro counts too if it is never opened
+        if day > OPEN_DOOR_DAYS
+            && self.stats.get_door_closes() == 0
+            && self.stats.get_closed_door_days() == 0
+        {
+            self.achievements.unlock(Achievement::OpenDoor);
+        }
+        if day > SOBER_DAYS && self.stats.get_meth_count() == 0 {
+            self.achievements.unlock(Achievement::Sober);
+        }
+        if self.company.employees_count() >= FULL_OFFICE_HEADCOUNT {
+            self.achievements.unlock(Achievement::FullOffice);
+        }
+        if self.stats.get_qte_refused() >= ALWAYS_NO_ANSWERS && self.stats.get_qte_accepted() == 0 {
+            self.achievements.unlock(Achievement::AlwaysNo);
+        }
+
+        // Nobody burned between the fire alarm and the end of the fires
+        if let Some(burned) = self.burned_at_alarm {
+            if self.company.fate_count(Fate::Burned) > burned {
+                self.burned_at_alarm = None;
+            } else if self.qte_ongoing.is_none() && !self.company.is_on_fire() {
+                self.burned_at_alarm = None;
+                self.achievements.unlock(Achievement::SafeEvacuation);
+            }
+        }
+    }
+
+    pub const fn get_achievements(&self) -> &Achievements {
+        &self.achievements
+    }
+
     pub fn get_start_door_cd(&self) -> f64 {
         self.door_start_cd
     }
@@ -457,12 +929,17 @@ impl Game {
         &self.qte_ongoing
     }
 
+    pub const fn get_company(&self) -> &Company {
+        &self.company
+    }
+
+    /// The floor currently shown
     pub fn get_office(&self) -> &Office {
-        &self.office
+        self.company.get_current()
     }
 
     pub fn get_mut_office(&mut self) -> &mut Office {
-        &mut self.office
+        self.company.get_current_mut()
     }
 
     pub fn get_answer(&self) -> &Option<String> {
@@ -473,22 +950,108 @@ impl Game {
         self.night_value
     }
 
+    /// From evening to dawn
+    pub const fn is_night(&self) -> bool {
+        matches!(self.day, Day::Evening | Day::Night)
+    }
+
     pub fn launch_qte(&mut self) -> Option<QTE> {
+        if self.company.jumped_count() >= 2
+            && self.get_office().get_window_state() != WindowState::Barricaded
+            && rand::gen_range(0, 3) == 0
+        {
+            self.launch_qte_kind(QteKind::Barricade)
+        } else {
+            self.launch_qte_kind(QteKind::Random)
+        }
+    }
+
+    pub fn launch_qte_kind(&mut self, kind: QteKind) -> Option<QTE> {
+        let night = self.is_night();
+        let day = self.get_office().get_day();
+        // Levels pick their random QTEs, the events can always happen
+        let level = self.level.as_ref().map(LevelRun::get_level);
+
+        let qtes = self
+            .qtes
+            .iter()
+            .filter(|q| q.get_kind() == kind && q.is_eligible(night, day))
+            .filter(|q| kind != QteKind::Random || level.is_none_or(|l| l.allows_qte(q)))
+            .collect::<Vec<&QTE>>();
+
+        if qtes.is_empty() {
+            return None;
+        }
+
         self.starting_time_qte = get_time() as f32;
-        let choosed = rand::gen_range(0, self.qtes.len());
+        let choosed = rand::gen_range(0, qtes.len());
+        let mut qte = qtes[choosed].clone();
+
+        // Endless runs leave less time to answer, and the big boss asks for more
+        let escalation = self.get_escalation();
+        qte.scale_time(escalation);
+        if qte.get_key() == Some("boss") {
+            qte.scale_effects(escalation);
+        }
+
+        Some(qte)
+    }
+
+    /// Applies the chosen answer of a QTE and records it in the employees history
+    pub fn answer_qte(&mut self, qte: &QTE, first_choice: bool) {
+        let (effect, choice, explication) = if first_choice {
+            (
+                qte.get_effect_1(),
+                qte.get_choice1(),
+                qte.get_explication1(),
+            )
+        } else {
+            (
+                qte.get_effect_2(),
+                qte.get_choice2(),
+                qte.get_explication2(),
+            )
+        };
+
+        let mut question = qte.get_text().chars().take(40).collect::<String>();
+        if question.len() < qte.get_text().len() {
+            question += "...";
+        }
+
+        self.company
+            .record_event(&HistoryEvent::Qte(format!("{question} {choice}")));
+        self.company.apply_qte_effect(effect);
+        self.stats.record_qte(first_choice);
+
+        // The answer must not cost any employee, and the fires neither
+        if qte.get_kind() == QteKind::FireAlarm && effect.employee_delta < 0 {
+            self.burned_at_alarm = None;
+        }
 
-        Some(self.qtes[choosed].clone())
+        // The evacuation lets the firefighters in
+        if qte.get_kind() == QteKind::FireAlarm && first_choice {
+            self.company.extinguish_all_fires();
+        }
+
+        if qte.get_kind() == QteKind::Barricade && first_choice {
+            self.get_mut_office()
+                .set_window_state(WindowState::Barricaded);
+        }
+        self.quit_qte(explication.to_string());
     }
 
     pub fn quit_qte(&mut self, answer: String) {
         self.qte_ongoing = None;
         self.answer = Some(answer);
         self.starting_time_answer = get_time() as f32;
-        self.next_time_qte = rand::gen_range(MIN_PERIOD_WITHOUT_QTE, MAX_PERIOD_WITHOUT_QTE);
+        let rules = self.company.get_rules();
+        self.next_time_qte = rand::gen_range(rules.min_qte_period, rules.max_qte_period)
+            * self.mode.get_qte_period_factor()
+            / self.get_escalation();
     }
 }
 
-const FPS: f32 = 60.;
+pub const FPS: f32 = 60.;
 
 fn window_conf() -> Conf {
     Conf {
@@ -500,6 +1063,7 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
+    assets::load_sounds().await;
     let mut game = Game::new();
 
     loop {
@@ -522,6 +1086,11 @@ enum MenuState {
     Start,
     CloudDispersing,
     GameOver,
+    HighScores,
+    Achievements,
+    LevelSelect,
+    /// The manager explains the campaign level before its intro
+    Briefing,
     IntroStart,
     IntroEmployeeEnter,
     IntroManagerWalk,
@@ -578,13 +1147,61 @@ impl Menu {
         match self.state {
             MenuState::Start => {
                 if is_mouse_button_pressed(MouseButton::Left) {
-                    let rect = Rect::new(0., 0., screen_width(), screen_height());
-                    let main_pos =
-                        Drawing::convert_screen_main(vec2(mouse_position().0, mouse_position().1));
+                    let mouse = vec2(mouse_position().0, mouse_position().1);
 
-                    if rect.contains(main_pos) {
+                    if ScreenLayout::current().menu.contains(mouse) {
                         self.state = MenuState::CloudDispersing;
                     }
+                } else if is_key_pressed(KeyCode::M) {
+                    game.mode = game.mode.next();
+                } else if is_key_pressed(KeyCode::D) {
+                    game.next_rules();
+                } else if is_key_pressed(KeyCode::H) {
+                    self.state = MenuState::HighScores;
+                } else if is_key_pressed(KeyCode::A) {
+                    self.state = MenuState::Achievements;
+                } else if is_key_pressed(KeyCode::C) {
+                    self.state = MenuState::LevelSelect;
+                }
+            }
+            MenuState::LevelSelect => {
+                let mouse =
+                    Drawing::convert_screen_menu(vec2(mouse_position().0, mouse_position().1));
+                let number_keys = [
+                    KeyCode::Key1,
+                    KeyCode::Key2,
+                    KeyCode::Key3,
+                    KeyCode::Key4,
+                    KeyCode::Key5,
+                ];
+
+                if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::C) {
+                    self.state = MenuState::Start;
+                } else if let Some(index) = (0..LEVELS.len()).find(|&i| {
+                    is_key_pressed(number_keys[i])
+                        || (is_mouse_button_pressed(MouseButton::Left)
+                            && Drawing::level_row_rect(i).contains(mouse))
+                }) {
+                    if game.campaign.is_unlocked(index) {
+                        game.start_level(index);
+                        self.state = MenuState::Briefing;
+                    }
+                }
+            }
+            MenuState::Briefing => {
+                if is_key_pressed(KeyCode::Escape) {
+                    game.restart();
+                } else if is_mouse_button_pressed(MouseButton::Left) {
+                    self.state = MenuState::CloudDispersing;
+                }
+            }
+            MenuState::HighScores | MenuState::Achievements => {
+                if is_mouse_button_pressed(MouseButton::Left)
+                    || is_key_pressed(KeyCode::Escape)
+                    || is_key_pressed(KeyCode::H)
+                    || is_key_pressed(KeyCode::A)
+                {
+                    self.state = MenuState::Start;
                 }
             }
 
@@ -611,29 +1228,31 @@ impl Menu {
                     let n_employees = 3;
 
                     (0..n_employees).for_each(|_| {
-                        game.office.add_employee_intro();
+                        game.get_mut_office().add_employee_intro();
                         (0..n).for_each(|_| {
-                            game.office.tick();
+                            game.company.tick();
                         });
                     });
                 }
 
                 (0..2).for_each(|_| {
-                    game.office.tick();
+                    game.company.tick();
                 });
             }
             MenuState::IntroStart => {
                 println!("start");
 
-                game.office.iter_employees_mut().for_each(|mut e| {
-                    e.is_state_freezed = true;
-                });
+                game.get_mut_office()
+                    .iter_employees_mut()
+                    .for_each(|mut e| {
+                        e.is_state_freezed = true;
+                    });
 
                 self.state = MenuState::IntroEmployeeEnter;
             }
             MenuState::IntroEmployeeEnter => {
                 (0..2).for_each(|_| {
-                    game.office.tick();
+                    game.company.tick();
                 });
 
                 if self.tick_count > 60 * 6 {
@@ -642,7 +1261,7 @@ impl Menu {
             }
             MenuState::IntroManagerWalk => {
                 (0..2).for_each(|_| {
-                    game.office.tick();
+                    game.company.tick();
                 });
 
                 self.manager_pos.y += self.manager_speed;
@@ -653,7 +1272,7 @@ impl Menu {
             }
             MenuState::IntroDoor => {
                 (0..2).for_each(|_| {
-                    game.office.tick();
+                    game.company.tick();
                 });
 
                 if !self.manager_rotated {
@@ -663,7 +1282,7 @@ impl Menu {
 
                 if self.tick_count > 60 * 10 && !self.door_closed {
                     self.door_closed = true;
-                    game.office.update_door();
+                    game.get_mut_office().update_door();
                 }
 
                 if self.tick_count > 60 * 11 {
@@ -676,7 +1295,7 @@ impl Menu {
             }
             MenuState::IntroManagerLeave => {
                 (0..1).for_each(|_| {
-                    game.office.tick();
+                    game.company.tick();
                 });
 
                 if self.manager_rotated {
@@ -688,7 +1307,7 @@ impl Menu {
 
                 if !self.kill_rand {
                     self.kill_rand = true;
-                    game.office.suicide_random_employee();
+                    game.get_mut_office().suicide_random_employee();
                 }
 
                 if self.tick_count > 60 * 14 {
@@ -696,9 +1315,11 @@ impl Menu {
                 }
             }
             MenuState::GameStart => {
-                game.office.iter_employees_mut().for_each(|mut e| {
-                    e.is_state_freezed = false;
-                });
+                game.get_mut_office()
+                    .iter_employees_mut()
+                    .for_each(|mut e| {
+                        e.is_state_freezed = false;
+                    });
 
                 self.game_started = true;
             }
@@ -724,9 +1345,13 @@ impl Menu {
         self.draw_clouds(game);
 
         match self.state {
-            MenuState::GameOver => (),
+            MenuState::GameOver
+            | MenuState::HighScores
+            | MenuState::Achievements
+            | MenuState::LevelSelect
+            | MenuState::Briefing => (),
             _ => {
-                if !self.crunch_mode {
+                if !self.crunch_mode && game.mode == GameMode::MyLittleOffice {
                     self.draw_logo1();
                 } else {
                     self.draw_logo2();
@@ -735,43 +1360,68 @@ impl Menu {
         }
 
         match self.state {
-            MenuState::Start => draw_text_ex(
-                "Click to start",
-                screen_width() / 2. - 250.,
-                screen_height() / 2. + 200.,
-                TextParams {
-                    font: Some(&assets::FONT),
-                    font_size: 100 as u16,
-                    color: BLACK,
-                    ..Default::default()
-                },
-            ),
-            MenuState::GameOver => {
-                if self.cloud1_pos.x < self.cloud1_start_pos.x {
-                    draw_text_ex(
-                        "Vous avez perdu !",
-                        100.,
-                        200.,
-                        TextParams {
-                            font: Some(&assets::FONT),
-                            font_size: 100 as u16,
-                            color: BLACK,
-                            ..Default::default()
-                        },
-                    );
+            MenuState::Start => {
+                draw_text_ex(
+                    "Click to start",
+                    screen_width() / 2. - 250.,
+                    screen_height() / 2. + 200.,
+                    TextParams {
+                        font: Some(&assets::FONT),
+                        font_size: 100_u16,
+                        color: BLACK,
+                        ..Default::default()
+                    },
+                );
+                let options = [
+                    "E : aménager le bureau".to_owned(),
+                    format!("M : mode {}", game.mode.get_name()),
+                    format!(
+                        "D : difficulté {}",
+                        game.company.get_rules().preset.get_name()
+                    ),
+                    "H : meilleurs scores".to_owned(),
+                    format!(
+                        "A : succès ({}/{})",
+                        game.achievements.unlocked_count(),
+                        Achievement::ALL.len()
+                    ),
+                    "C : campagne".to_owned(),
+                ];
+                for (i, option) in options.iter().enumerate() {
                     draw_text_ex(
-                        "Vous n'aviez plus d'employés à exploiter !",
-                        100.,
-                        300.,
+                        option,
+                        screen_width() / 2. - 150.,
+                        (i as f32).mul_add(50., screen_height() / 2. + 270.),
                         TextParams {
                             font: Some(&assets::FONT),
-                            font_size: 50 as u16,
-                            color: BLACK,
+                            font_size: 40_u16,
+                            color: DARKGRAY,
                             ..Default::default()
                         },
                     );
                 }
             }
+            MenuState::HighScores => {
+                game.drawing.borrow().draw_high_scores(&game.high_scores);
+            }
+            MenuState::Achievements => {
+                game.drawing.borrow().draw_achievements(&game.achievements);
+            }
+            MenuState::LevelSelect => {
+                game.drawing.borrow().draw_level_select(&game.campaign);
+            }
+            MenuState::Briefing => {
+                if let Some(level) = &game.level {
+                    game.drawing.borrow().draw_briefing(level.get_level());
+                }
+            }
+            MenuState::GameOver if self.cloud1_pos.x < self.cloud1_start_pos.x => {
+                if let Some(summary) = &game.summary {
+                    game.drawing
+                        .borrow()
+                        .draw_run_summary(summary, game.rank, game.get_level());
+                }
+            }
             MenuState::IntroManagerWalk | MenuState::IntroManagerLeave | MenuState::IntroDoor => {
                 draw_texture_ex(
                     &assets::MANAGER_TEXTURE,
@@ -789,6 +1439,11 @@ impl Menu {
         }
     }
 
+    /// The clouds of the game over are back over the office
+    pub fn are_clouds_closed(&self) -> bool {
+        self.cloud1_pos.x <= self.cloud1_start_pos.x && self.cloud2_pos.x >= self.cloud2_start_pos.x
+    }
+
     pub fn draw_logo1(&mut self) {
         draw_texture_ex(
             &assets::LOGO1_TEXTURE,
@@ -0,0 +1,333 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use macroquad::math::{Rect, Vec2};
+
+use crate::{
+    drawing::{OFFICE_HEIGHT, OFFICE_WIDTH},
+    layout::Layout,
+};
+
+pub const CELL_SIZE: f32 = 20.;
+
+/// Where people come from, at the bottom of the corridor
+pub const ENTRANCE: Vec2 = Vec2::new(220., 750.);
+/// Where people leave, at the top of the corridor
+pub const EXIT: Vec2 = Vec2::new(250., -60.);
+
+/// Cost of a straight and a diagonal step between two cells
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+/// Margin kept between the employees and the furniture
+const OBSTACLE_MARGIN: f32 = 10.;
+
+/// Areas of the office where employees can walk
+const OFFICE_WALKABLE: [Rect; 4] = [
+    // Room
+    Rect::new(355., 80., 685., 560.),
+    // Door
+    Rect::new(280., 320., 90., 80.),
+    // Corridor
+    Rect::new(180., 0., 100., 720.),
+    // Window
+    Rect::new(1040., 300., 60., 100.),
+];
+
+/// Grid of the walkable cells of the office, used to find paths between two points
+pub struct NavGrid {
+    columns: usize,
+    rows: usize,
+    walkable: Vec<bool>,
+}
+
+impl NavGrid {
+    pub fn new(walkable: &[Rect], obstacles: &[Rect]) -> Self {
+        let columns = (OFFICE_WIDTH as f32 / CELL_SIZE).ceil() as usize;
+        let rows = (OFFICE_HEIGHT as f32 / CELL_SIZE).ceil() as usize;
+
+        let obstacles = obstacles
+            .iter()
+            .map(|r| {
+                Rect::new(
+                    r.x - OBSTACLE_MARGIN,
+                    r.y - OBSTACLE_MARGIN,
+                    2.0f32.mul_add(OBSTACLE_MARGIN, r.w),
+                    2.0f32.mul_add(OBSTACLE_MARGIN, r.h),
+                )
+            })
+            .collect::<Vec<Rect>>();
+
+        let mut grid = Self {
+            columns,
+            rows,
+            walkable: vec![false; columns * rows],
+        };
+
+        for cell in 0..grid.walkable.len() {
+            let center = grid.cell_center(cell);
+            grid.walkable[cell] = walkable.iter().any(|r| r.contains(center))
+                && !obstacles.iter().any(|r| r.contains(center));
+        }
+
+        grid
+    }
+
+    /// The office with the desks of the layout
+    pub fn for_layout(layout: &Layout) -> Self {
+        Self::new(&OFFICE_WALKABLE, &layout.get_desk_rects())
+    }
+
+    fn cell_center(&self, cell: usize) -> Vec2 {
+        Vec2::new(
+            ((cell % self.columns) as f32 + 0.5) * CELL_SIZE,
+            ((cell / self.columns) as f32 + 0.5) * CELL_SIZE,
+        )
+    }
+
+    fn cell_at(&self, pos: Vec2) -> Option<usize> {
+        if pos.x < 0. || pos.y < 0. {
+            return None;
+        }
+
+        let column = (pos.x / CELL_SIZE) as usize;
+        let row = (pos.y / CELL_SIZE) as usize;
+
+        if column < self.columns && row < self.rows {
+            Some(row * self.columns + column)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_walkable(&self, pos: Vec2) -> bool {
+        self.cell_at(pos).is_some_and(|cell| self.walkable[cell])
+    }
+
+    fn nearest_walkable(&self, pos: Vec2) -> Option<usize> {
+        if let Some(cell) = self.cell_at(pos).filter(|&cell| self.walkable[cell]) {
+            return Some(cell);
+        }
+
+        (0..self.walkable.len())
+            .filter(|&cell| self.walkable[cell])
+            .min_by(|&a, &b| {
+                self.cell_center(a)
+                    .distance_squared(pos)
+                    .total_cmp(&self.cell_center(b).distance_squared(pos))
+            })
+    }
+
+    fn line_of_sight(&self, from: Vec2, to: Vec2) -> bool {
+        let steps = (from.distance(to) / (CELL_SIZE / 4.)).ceil() as usize;
+
+        (0..=steps).all(|i| self.is_walkable(from.lerp(to, i as f32 / steps.max(1) as f32)))
+    }
+
+    fn neighbours(&self, cell: usize) -> impl Iterator<Item = (usize, u32)> + '_ {
+        let column = cell % self.columns;
+        let row = cell / self.columns;
+
+        [
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (1, -1),
+            (-1, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy): (isize, isize)| {
+            // Index of the cell at this offset, if it is in the grid and walkable
+            let walkable = |dx: isize, dy: isize| {
+                let c = column
+                    .checked_add_signed(dx)
+                    .filter(|&c| c < self.columns)?;
+                let r = row.checked_add_signed(dy).filter(|&r| r < self.rows)?;
+                let index = r * self.columns + c;
+                self.walkable[index].then_some(index)
+            };
+
+            let index = walkable(dx, dy)?;
+
+            if dx != 0 && dy != 0 {
+                // Do not cut the corners of the furniture
+                walkable(dx, 0)?;
+                walkable(0, dy)?;
+                Some((index, DIAGONAL_COST))
+            } else {
+                Some((index, STRAIGHT_COST))
+            }
+        })
+    }
+
+    fn heuristic(&self, a: usize, b: usize) -> u32 {
+        let dx = (a % self.columns).abs_diff(b % self.columns) as u32;
+        let dy = (a / self.columns).abs_diff(b / self.columns) as u32;
+
+        STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+    }
+
+    /// A* between the cells of the two points. The returned waypoints are
+    /// smoothed and end exactly on `to`. Returns `None` if `to` can not be
+    /// reached from `from`.
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+        let start = self.nearest_walkable(from)?;
+        let goal = self.nearest_walkable(to)?;
+
+        let mut costs = vec![u32::MAX; self.walkable.len()];
+        let mut came_from = vec![usize::MAX; self.walkable.len()];
+        let mut open = BinaryHeap::new();
+
+        costs[start] = 0;
+        open.push(Reverse((self.heuristic(start, goal), start)));
+
+        while let Some(Reverse((_, cell))) = open.pop() {
+            if cell == goal {
+                break;
+            }
+
+            for (next, step_cost) in self.neighbours(cell) {
+                let cost = costs[cell] + step_cost;
+                if cost < costs[next] {
+                    costs[next] = cost;
+                    came_from[next] = cell;
+                    open.push(Reverse((cost + self.heuristic(next, goal), next)));
+                }
+            }
+        }
+
+        if costs[goal] == u32::MAX {
+            return None;
+        }
+
+        let mut cells = vec![goal];
+        while let Some(&cell) = cells.last() {
+            if cell == start {
+                break;
+            }
+            cells.push(came_from[cell]);
+        }
+
+        let mut points = vec![from];
+        points.extend(cells.iter().rev().map(|&cell| self.cell_center(cell)));
+        points.push(to);
+
+        Some(self.smooth(&points))
+    }
+
+    /// Skips the waypoints that can be seen from the previous kept one
+    fn smooth(&self, points: &[Vec2]) -> Vec<Vec2> {
+        let mut path = Vec::new();
+        let mut anchor = 0;
+
+        while anchor < points.len() - 1 {
+            let next = (anchor + 2..points.len())
+                .rev()
+                .find(|&i| self.line_of_sight(points[anchor], points[i]))
+                .unwrap_or(anchor + 1);
+
+            path.push(points[next]);
+            anchor = next;
+        }
+
+        path
+    }
+}
+
+/// Moves toward the next waypoint of the path, and returns the rotation of the movement
+pub fn follow_path(position: &mut Vec2, path: &mut VecDeque<Vec2>, speed: f32) -> Option<f32> {
+    let &target = path.front()?;
+
+    let direction = target - *position;
+
+    if direction.length() <= speed {
+        *position = target;
+        path.pop_front();
+    } else {
+        *position += direction.normalize() * speed;
+    }
+
+    if direction == Vec2::ZERO {
+        None
+    } else {
+        Some(direction.y.atan2(direction.x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOM: Rect = Rect::new(0., 0., 400., 400.);
+
+    fn assert_walkable_path(grid: &NavGrid, from: Vec2, path: &[Vec2]) {
+        let mut previous = from;
+        for &point in path {
+            assert!(grid.line_of_sight(previous, point), "{previous} -> {point}");
+            previous = point;
+        }
+    }
+
+    #[test]
+    fn straight_line_in_an_empty_room() {
+        let grid = NavGrid::new(&[ROOM], &[]);
+        let to = Vec2::new(350., 50.);
+
+        assert_eq!(grid.find_path(Vec2::new(50., 50.), to), Some(vec![to]));
+    }
+
+    #[test]
+    fn path_goes_around_obstacles() {
+        // A wall in the middle of the room, open at the bottom
+        let grid = NavGrid::new(&[ROOM], &[Rect::new(190., 0., 20., 300.)]);
+        let from = Vec2::new(50., 50.);
+        let to = Vec2::new(350., 50.);
+
+        let path = grid.find_path(from, to).unwrap();
+
+        assert_eq!(path.last(), Some(&to));
+        assert!(path.iter().any(|p| p.y > 310.));
+        assert_walkable_path(&grid, from, &path);
+    }
+
+    #[test]
+    fn no_path_between_separate_areas() {
+        let grid = NavGrid::new(
+            &[
+                Rect::new(0., 0., 200., 200.),
+                Rect::new(300., 0., 200., 200.),
+            ],
+            &[],
+        );
+
+        assert_eq!(
+            grid.find_path(Vec2::new(50., 50.), Vec2::new(400., 50.)),
+            None
+        );
+    }
+
+    #[test]
+    fn no_path_through_a_closed_wall() {
+        let grid = NavGrid::new(&[ROOM], &[Rect::new(190., 0., 20., 400.)]);
+
+        assert_eq!(
+            grid.find_path(Vec2::new(50., 50.), Vec2::new(350., 50.)),
+            None
+        );
+    }
+
+    #[test]
+    fn every_seat_of_the_default_office_is_reachable() {
+        let layout = Layout::default();
+        let grid = NavGrid::for_layout(&layout);
+
+        for computer in layout.build_computers() {
+            assert!(grid.find_path(ENTRANCE, computer.spot).is_some());
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use macroquad::{color::Color, rand::gen_range};
+
+use crate::employee::Stat;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trait {
+    /// Loves working, hates resting
+    Workaholic,
+    /// Always hungry
+    Glutton,
+    /// Lives for their family
+    FamilyPerson,
+    /// Always sees the glass half empty
+    Pessimist,
+    /// Will not leave the company
+    Loyal,
+    /// Will not take it anymore
+    Rebellious,
+}
+
+const TRAITS: [Trait; 6] = [
+    Trait::Workaholic,
+    Trait::Glutton,
+    Trait::FamilyPerson,
+    Trait::Pessimist,
+    Trait::Loyal,
+    Trait::Rebellious,
+];
+
+impl Trait {
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Workaholic => "Bourreau de travail",
+            Self::Glutton => "Gourmand",
+            Self::FamilyPerson => "Famille",
+            Self::Pessimist => "Pessimiste",
+            Self::Loyal => "Loyal",
+            Self::Rebellious => "Rebelle",
+        }
+    }
+
+    pub const fn get_description(self) -> &'static str {
+        match self {
+            Self::Workaholic => "Ne se lasse jamais du travail, mais s'épuise plus vite. Un trop plein d'énergie reste dangereux pour le matériel.",
+            Self::Glutton => "A toujours faim. Sa sasiété baisse plus vite et les histoires de nourriture le touchent beaucoup.",
+            Self::FamilyPerson => "Ne vit que pour sa famille. La porte fermée lui fait perdre espoir bien plus vite, mais un appel le requinque.",
+            Self::Pessimist => "Voit toujours le verre à moitié vide. Perd espoir plus vite et en regagne moins.",
+            Self::Loyal => "Ne quittera jamais l'entreprise tant qu'il lui reste un peu de joie. Supporte mieux la porte fermée.",
+            Self::Rebellious => "Ne se laisse pas faire. S'énerve plus vite, casse son ordinateur et fuit plus facilement.",
+        }
+    }
+
+    pub const fn get_color(self) -> Color {
+        match self {
+            Self::Workaholic => Color::new(0.95, 0.75, 0.2, 1.),
+            Self::Glutton => Color::new(0.55, 0.75, 0.95, 1.),
+            Self::FamilyPerson => Color::new(0.5, 0.85, 0.5, 1.),
+            Self::Pessimist => Color::new(0.65, 0.65, 0.7, 1.),
+            Self::Loyal => Color::new(0.75, 0.6, 0.95, 1.),
+            Self::Rebellious => Color::new(0.95, 0.45, 0.4, 1.),
+        }
+    }
+
+    const fn decay_factor(self, stat: Stat) -> f32 {
+        match (self, stat) {
+            (Self::Workaholic, Stat::Satisfaction) => 0.6,
+            (Self::Workaholic, Stat::Energy)
+            | (Self::Pessimist, Stat::Hope)
+            | (Self::Rebellious, Stat::Satisfaction) => 1.3,
+            (Self::Glutton, Stat::Satiety) => 1.6,
+            _ => 1.,
+        }
+    }
+
+    const fn replenish_factor(self, stat: Stat) -> f32 {
+        match (self, stat) {
+            (Self::Workaholic, Stat::Satisfaction) | (Self::Pessimist, Stat::Hope) => 0.5,
+            (Self::Glutton, Stat::Satiety) | (Self::FamilyPerson, Stat::Hope) => 1.5,
+            _ => 1.,
+        }
+    }
+
+    fn qte_factor(self, stat: Stat, delta: f32) -> f32 {
+        match (self, stat) {
+            (Self::Pessimist, Stat::Hope) if delta > 0. => 0.5,
+            (Self::Glutton, Stat::Satiety) | (Self::FamilyPerson | Self::Pessimist, Stat::Hope) => {
+                1.5
+            }
+            (Self::Loyal, Stat::Satisfaction) if delta < 0. => 0.5,
+            (Self::Rebellious, Stat::Satisfaction) if delta < 0. => 1.5,
+            _ => 1.,
+        }
+    }
+
+    const fn door_closed_hope_factor(self) -> f32 {
+        match self {
+            Self::FamilyPerson => 1.5,
+            Self::Loyal => 0.5,
+            _ => 1.,
+        }
+    }
+
+    const fn door_open_hope_factor(self) -> f32 {
+        match self {
+            Self::Pessimist => 0.5,
+            _ => 1.,
+        }
+    }
+
+    fn conflicts_with(self, other: Self) -> bool {
+        self == other
+            || matches!(
+                (self, other),
+                (Self::Loyal, Self::Rebellious) | (Self::Rebellious, Self::Loyal)
+            )
+    }
+}
+
+/// The set of traits of an employee, and how they combine
+#[derive(Clone, Debug)]
+pub struct Personality {
+    traits: Vec<Trait>,
+}
+
+impl Personality {
+    pub fn random() -> Self {
+        let mut traits = vec![TRAITS[gen_range(0, TRAITS.len())]];
+
+        if gen_range(0, 2) == 0 {
+            let second = TRAITS[gen_range(0, TRAITS.len())];
+            if !second.conflicts_with(traits[0]) {
+                traits.push(second);
+            }
+        }
+
+        Self { traits }
+    }
+
+    pub fn iter_traits(&self) -> impl Iterator<Item = &Trait> {
+        self.traits.iter()
+    }
+
+    pub fn has_trait(&self, t: Trait) -> bool {
+        self.traits.contains(&t)
+    }
+
+    pub fn decay_factor(&self, stat: Stat) -> f32 {
+        self.traits.iter().map(|t| t.decay_factor(stat)).product()
+    }
+
+    pub fn replenish_factor(&self, stat: Stat) -> f32 {
+        self.traits
+            .iter()
+            .map(|t| t.replenish_factor(stat))
+            .product()
+    }
+
+    /// Modified stat change when a QTE or a bonus affects the employee
+    pub fn qte_reaction(&self, stat: Stat, delta: f32) -> f32 {
+        delta
+            * self
+                .traits
+                .iter()
+                .map(|t| t.qte_factor(stat, delta))
+                .product::<f32>()
+    }
+
+    pub fn door_closed_hope_factor(&self) -> f32 {
+        self.traits
+            .iter()
+            .map(|t| t.door_closed_hope_factor())
+            .product()
+    }
+
+    pub fn door_open_hope_factor(&self) -> f32 {
+        self.traits
+            .iter()
+            .map(|t| t.door_open_hope_factor())
+            .product()
+    }
+
+    /// Satisfaction under which the employee breaks their computer
+    pub fn break_computer_satisfaction(&self) -> f32 {
+        if self.has_trait(Trait::Rebellious) {
+            0.15
+        } else {
+            0.
+        }
+    }
+
+    /// Hope above which the employee tries to flee
+    pub fn flee_hope(&self) -> f32 {
+        if self.has_trait(Trait::Loyal) {
+            // Hope is clamped to 1, a loyal employee never flees out of hope
+            f32::INFINITY
+        } else if self.has_trait(Trait::Rebellious) {
+            0.85
+        } else {
+            1.
+        }
+    }
+
+    /// Satisfaction under which the employee tries to flee
+    pub fn flee_satisfaction(&self) -> f32 {
+        if self.has_trait(Trait::Rebellious) {
+            0.1
+        } else {
+            0.
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::employee::{Employee, EmployeeAction, EmployeeState, Stat};
+
+/// Most rules an office can have
+pub const MAX_POLICIES: usize = 6;
+
+/// Daily cost of a rule for everyone, and of a rule for a single employee
+const GLOBAL_POLICY_UPKEEP: f32 = 60.;
+const EMPLOYEE_POLICY_UPKEEP: f32 = 15.;
+
+/// Ticks an employee works after an automated action before the next one
+const POLICY_COOLDOWN: u32 = 300;
+
+/// A stat triggered action goes on until the stat gained this much
+const POLICY_RECOVERY: f32 = 0.4;
+const POLICY_FULL: f32 = 0.9;
+
+/// Length of the daily routines, in ticks spent in the zone of the action
+const ROUTINE_TICKS: u32 = 120;
+
+/// Step of the threshold buttons of the stat rules
+const THRESHOLD_STEP: f32 = 0.1;
+
+/// When a rule starts its action
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Trigger {
+    /// The stat replenished by the action is below the value
+    StatBelow(f32),
+    /// From evening to dawn
+    Night,
+    /// Once at the start of every other day
+    EveryOtherDay,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolicyTarget {
+    Everyone,
+    /// A single employee, by id and name
+    Employee(u64, String),
+}
+
+impl PolicyTarget {
+    pub fn get_name(&self) -> &str {
+        match self {
+            Self::Everyone => "Tous",
+            Self::Employee(_, name) => name,
+        }
+    }
+
+    /// What a rule for the target costs each day
+    pub const fn get_upkeep(&self) -> f32 {
+        match self {
+            Self::Everyone => GLOBAL_POLICY_UPKEEP,
+            Self::Employee(..) => EMPLOYEE_POLICY_UPKEEP,
+        }
+    }
+}
+
+/// What the rules read from and change on an employee
+pub trait Worker {
+    fn get_id(&self) -> u64;
+    /// Alive, at work, and not frozen by the intro
+    fn is_available(&self) -> bool;
+    fn get_action(&self) -> EmployeeAction;
+    fn set_action(&mut self, action: EmployeeAction);
+    fn get_stat(&self, stat: Stat) -> f32;
+    /// Doing the action in its zone, and not walking to it or waiting in the queue
+    fn is_using_zone(&self) -> bool;
+}
+
+impl Worker for Employee {
+    fn get_id(&self) -> u64 {
+        Self::get_id(self)
+    }
+
+    fn is_available(&self) -> bool {
+        matches!(self.get_state(), EmployeeState::Alive) && !self.is_state_freezed()
+    }
+
+    fn get_action(&self) -> EmployeeAction {
+        self.action
+    }
+
+    fn set_action(&mut self, action: EmployeeAction) {
+        self.action = action;
+    }
+
+    fn get_stat(&self, stat: Stat) -> f32 {
+        Self::get_stat(self, stat)
+    }
+
+    fn is_using_zone(&self) -> bool {
+        Self::is_using_zone(self)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Policy {
+    id: u32,
+    pub action: EmployeeAction,
+    pub trigger: Trigger,
+    pub target: PolicyTarget,
+    pub enabled: bool,
+}
+
+/// The rules the player can add
+pub const TEMPLATES: [(EmployeeAction, Trigger); 6] = [
+    (EmployeeAction::Eat, Trigger::StatBelow(0.3)),
+    (EmployeeAction::Sleep, Trigger::StatBelow(0.3)),
+    (EmployeeAction::Break, Trigger::StatBelow(0.3)),
+    (EmployeeAction::FamilyCall, Trigger::StatBelow(0.3)),
+    (EmployeeAction::Sleep, Trigger::Night),
+    (EmployeeAction::FamilyCall, Trigger::EveryOtherDay),
+];
+
+/// "Manger si sasiété < 30%", without the target
+pub fn describe_rule(action: EmployeeAction, trigger: Trigger) -> String {
+    let verb = match action {
+        EmployeeAction::Eat => "Manger",
+        EmployeeAction::Sleep | EmployeeAction::ForcedSleep => "Dormir",
+        EmployeeAction::Break => "Pause",
+        EmployeeAction::FamilyCall => "Appeler sa famille",
+        EmployeeAction::None => "Travailler",
+    };
+
+    match (trigger, action.get_stat()) {
+        (Trigger::StatBelow(value), Some(stat)) => format!(
+            "{} si {} < {:.0}%",
+            verb,
+            stat.get_name().to_lowercase(),
+            value * 100.
+        ),
+        (Trigger::StatBelow(_), None) => verb.to_owned(),
+        (Trigger::Night, _) => format!("{verb} du soir à l'aube"),
+        (Trigger::EveryOtherDay, _) => format!("{verb} un jour sur deux"),
+    }
+}
+
+impl Policy {
+    pub fn describe(&self) -> String {
+        format!(
+            "{} : {}",
+            self.target.get_name(),
+            describe_rule(self.action, self.trigger).to_lowercase()
+        )
+    }
+
+    fn applies_to(&self, employee: &impl Worker) -> bool {
+        match self.target {
+            PolicyTarget::Everyone => true,
+            PolicyTarget::Employee(id, _) => id == employee.get_id(),
+        }
+    }
+
+    fn stat_value(&self, employee: &impl Worker) -> f32 {
+        self.action
+            .get_stat()
+            .map_or(1., |stat: Stat| employee.get_stat(stat))
+    }
+}
+
+/// An action an employee is doing because of a rule
+struct Run {
+    policy: u32,
+    action: EmployeeAction,
+    /// Ticks spent doing the action in its zone
+    ticks: u32,
+}
+
+/// The automation rules of an office, and the actions they started
+#[derive(Default)]
+pub struct Policies {
+    rules: Vec<Policy>,
+    next_id: u32,
+    runs: HashMap<u64, Run>,
+    cooldowns: HashMap<u64, u32>,
+    /// Last day each daily routine ran, by employee and rule
+    routines: HashMap<(u64, u32), u32>,
+}
+
+impl Policies {
+    pub fn add(&mut self, action: EmployeeAction, trigger: Trigger, target: PolicyTarget) {
+        if self.rules.len() >= MAX_POLICIES {
+            return;
+        }
+
+        self.next_id += 1;
+        self.rules.push(Policy {
+            id: self.next_id,
+            action,
+            trigger,
+            target,
+            enabled: true,
+        });
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index < self.rules.len() {
+            self.rules.remove(index);
+        }
+    }
+
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(rule) = self.rules.get_mut(index) {
+            rule.enabled = !rule.enabled;
+        }
+    }
+
+    /// Moves the threshold of a stat rule by `THRESHOLD_STEP` steps
+    pub fn adjust(&mut self, index: usize, steps: f32) {
+        if let Some(Policy {
+            trigger: Trigger::StatBelow(value),
+            ..
+        }) = self.rules.get_mut(index)
+        {
+            *value = steps
+                .mul_add(THRESHOLD_STEP, *value)
+                .clamp(THRESHOLD_STEP, POLICY_FULL);
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Policy> {
+        self.rules.iter()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.rules.len() >= MAX_POLICIES
+    }
+
+    /// What the enabled rules cost each day
+    pub fn get_upkeep(&self) -> f32 {
+        self.rules
+            .iter()
+            .filter(|r| r.enabled)
+            .map(|r| r.target.get_upkeep())
+            .sum()
+    }
+
+    /// Forgets what the rules did to an employee who left the office
+    pub fn forget(&mut self, employee_id: u64) {
+        self.runs.remove(&employee_id);
+        self.cooldowns.remove(&employee_id);
+        self.routines.retain(|(id, _), _| *id != employee_id);
+        self.rules
+            .retain(|r| !matches!(r.target, PolicyTarget::Employee(id, _) if id == employee_id));
+    }
+
+    /// Forgets every employee and their rules, but keeps the rules for everyone
+    pub fn reset(&mut self) {
+        self.runs.clear();
+        self.cooldowns.clear();
+        self.routines.clear();
+        self.rules.retain(|r| r.target == PolicyTarget::Everyone);
+    }
+
+    fn should_stop(&self, run: &Run, employee: &impl Worker, night: bool) -> bool {
+        let Some(rule) = self.rules.iter().find(|r| r.id == run.policy && r.enabled) else {
+            return true;
+        };
+
+        match rule.trigger {
+            Trigger::StatBelow(value) => {
+                rule.stat_value(employee) >= (value + POLICY_RECOVERY).min(POLICY_FULL)
+            }
+            Trigger::Night => !night,
+            Trigger::EveryOtherDay => run.ticks >= ROUTINE_TICKS,
+        }
+    }
+
+    fn is_triggered(&self, rule: &Policy, employee: &impl Worker, night: bool, day: u32) -> bool {
+        match rule.trigger {
+            Trigger::StatBelow(value) => rule.stat_value(employee) < value,
+            Trigger::Night => night,
+            Trigger::EveryOtherDay => {
+                day.is_multiple_of(2)
+                    && self.routines.get(&(employee.get_id(), rule.id)) != Some(&day)
+            }
+        }
+    }
+
+    /// Starts and stops the actions of the rules. Actions the player chose by
+    /// hand are left alone.
+    pub fn tick<W: Worker>(&mut self, employees: &[Rc<RefCell<W>>], night: bool, day: u32) {
+        self.cooldowns.retain(|_, ticks| {
+            *ticks -= 1;
+            *ticks > 0
+        });
+
+        for e in employees {
+            let mut e = e.borrow_mut();
+            let id = e.get_id();
+
+            if !e.is_available() {
+                continue;
+            }
+
+            if let Some(mut run) = self.runs.remove(&id) {
+                // The player or a forced sleep took over
+                if e.get_action() != run.action {
+                    continue;
+                }
+
+                // The walk and the queue do not count
+                if e.is_using_zone() {
+                    run.ticks += 1;
+                }
+                if self.should_stop(&run, &*e, night) {
+                    e.set_action(EmployeeAction::None);
+                    self.cooldowns.insert(id, POLICY_COOLDOWN);
+                } else {
+                    self.runs.insert(id, run);
+                }
+                continue;
+            }
+
+            if e.get_action() != EmployeeAction::None || self.cooldowns.contains_key(&id) {
+                continue;
+            }
+
+            let Some(rule) = self
+                .rules
+                .iter()
+                .find(|r| r.enabled && r.applies_to(&*e) && self.is_triggered(r, &*e, night, day))
+            else {
+                continue;
+            };
+
+            if rule.trigger == Trigger::EveryOtherDay {
+                self.routines.insert((id, rule.id), day);
+            }
+
+            e.set_action(rule.action);
+            self.runs.insert(
+                id,
+                Run {
+                    policy: rule.id,
+                    action: rule.action,
+                    ticks: 0,
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeWorker {
+        id: u64,
+        action: EmployeeAction,
+        hope: f32,
+        energy: f32,
+        using_zone: bool,
+    }
+
+    impl Worker for FakeWorker {
+        fn get_id(&self) -> u64 {
+            self.id
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn get_action(&self) -> EmployeeAction {
+            self.action
+        }
+
+        fn set_action(&mut self, action: EmployeeAction) {
+            self.action = action;
+        }
+
+        fn get_stat(&self, stat: Stat) -> f32 {
+            match stat {
+                Stat::Hope => self.hope,
+                Stat::Energy => self.energy,
+                Stat::Satisfaction | Stat::Satiety => 0.5,
+            }
+        }
+
+        fn is_using_zone(&self) -> bool {
+            self.using_zone
+        }
+    }
+
+    fn workers(count: u64) -> Vec<Rc<RefCell<FakeWorker>>> {
+        (1..=count)
+            .map(|id| {
+                Rc::new(RefCell::new(FakeWorker {
+                    id,
+                    action: EmployeeAction::None,
+                    hope: 0.5,
+                    energy: 0.5,
+                    using_zone: false,
+                }))
+            })
+            .collect()
+    }
+
+    fn action(worker: &Rc<RefCell<FakeWorker>>) -> EmployeeAction {
+        worker.borrow().action
+    }
+
+    #[test]
+    fn stat_rule_runs_until_the_stat_recovered() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::Sleep,
+            Trigger::StatBelow(0.3),
+            PolicyTarget::Everyone,
+        );
+        let workers = workers(2);
+        workers[0].borrow_mut().energy = 0.2;
+
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::Sleep);
+        assert_eq!(action(&workers[1]), EmployeeAction::None);
+
+        workers[0].borrow_mut().energy = 0.6;
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::Sleep);
+
+        workers[0].borrow_mut().energy = 0.75;
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+
+        // Back to work for a while, even with a low stat
+        workers[0].borrow_mut().energy = 0.1;
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+    }
+
+    #[test]
+    fn night_rule_stops_at_dawn() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::Sleep,
+            Trigger::Night,
+            PolicyTarget::Everyone,
+        );
+        let workers = workers(1);
+
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+
+        policies.tick(&workers, true, 1);
+        policies.tick(&workers, true, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::Sleep);
+
+        policies.tick(&workers, false, 2);
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+    }
+
+    #[test]
+    fn routine_only_counts_the_time_in_the_zone() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::FamilyCall,
+            Trigger::EveryOtherDay,
+            PolicyTarget::Everyone,
+        );
+        let workers = workers(1);
+
+        policies.tick(&workers, false, 1);
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+
+        // Walking to the booth and waiting in the queue
+        for _ in 0..ROUTINE_TICKS * 3 {
+            policies.tick(&workers, false, 2);
+        }
+        assert_eq!(action(&workers[0]), EmployeeAction::FamilyCall);
+
+        workers[0].borrow_mut().using_zone = true;
+        for _ in 0..ROUTINE_TICKS {
+            policies.tick(&workers, false, 2);
+        }
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+
+        // Once a day only
+        for _ in 0..=POLICY_COOLDOWN {
+            policies.tick(&workers, false, 2);
+        }
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+    }
+
+    #[test]
+    fn player_choice_takes_over() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::Sleep,
+            Trigger::Night,
+            PolicyTarget::Everyone,
+        );
+        let workers = workers(1);
+
+        policies.tick(&workers, true, 1);
+        workers[0].borrow_mut().action = EmployeeAction::Eat;
+        policies.tick(&workers, false, 2);
+
+        assert_eq!(action(&workers[0]), EmployeeAction::Eat);
+    }
+
+    #[test]
+    fn employee_rules_only_apply_to_them() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::Sleep,
+            Trigger::Night,
+            PolicyTarget::Employee(2, "Léa".to_owned()),
+        );
+        let workers = workers(2);
+
+        policies.tick(&workers, true, 1);
+
+        assert_eq!(action(&workers[0]), EmployeeAction::None);
+        assert_eq!(action(&workers[1]), EmployeeAction::Sleep);
+    }
+
+    #[test]
+    fn rules_of_employees_who_left_are_dropped() {
+        let mut policies = Policies::default();
+        policies.add(
+            EmployeeAction::Sleep,
+            Trigger::Night,
+            PolicyTarget::Everyone,
+        );
+        policies.add(
+            EmployeeAction::Eat,
+            Trigger::StatBelow(0.3),
+            PolicyTarget::Employee(1, "Léa".to_owned()),
+        );
+        policies.add(
+            EmployeeAction::Eat,
+            Trigger::StatBelow(0.3),
+            PolicyTarget::Employee(2, "Marc".to_owned()),
+        );
+
+        policies.forget(1);
+        assert_eq!(policies.len(), 2);
+        assert!(
+            (policies.get_upkeep() - GLOBAL_POLICY_UPKEEP - EMPLOYEE_POLICY_UPKEEP).abs()
+                < f32::EPSILON
+        );
+
+        policies.reset();
+        assert_eq!(policies.len(), 1);
+        assert!((policies.get_upkeep() - GLOBAL_POLICY_UPKEEP).abs() < f32::EPSILON);
+    }
+}
@@ -1,3 +1,8 @@
+use crate::calendar::{self, Holiday, Season};
+
+/// Seconds left to answer a QTE, however hard the run got
+const MIN_QTE_TIME: f32 = 1.;
+
 #[derive(Clone)]
 pub struct QteEffect {
     pub satisfaction_delta: f32,
@@ -9,7 +14,7 @@ pub struct QteEffect {
 }
 
 impl QteEffect {
-    pub fn new(
+    pub const fn new(
         satisfaction_delta: f32,
         energy_delta: f32,
         satiety_delta: f32,
@@ -26,10 +31,66 @@ impl QteEffect {
             employee_delta,
         }
     }
+
+    /// The stats and money change `factor` times more, the employees do not
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            satisfaction_delta: self.satisfaction_delta * factor,
+            energy_delta: self.energy_delta * factor,
+            satiety_delta: self.satiety_delta * factor,
+            hope_delta: self.hope_delta * factor,
+            money_delta: self.money_delta * factor,
+            employee_delta: self.employee_delta,
+        }
+    }
+}
+
+/// What launches a QTE
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QteKind {
+    /// Picked at random during the day
+    Random,
+    /// Launched when a fire gets big enough
+    FireAlarm,
+    /// Can only be picked once employees jumped out of the window
+    Barricade,
+    /// Launched on the morning of its holiday
+    Holiday,
+}
+
+/// When a QTE can be picked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QteMoment {
+    Always,
+    /// From evening to dawn
+    Night,
+    /// Monday to friday
+    Weekday,
+    /// Saturday and sunday
+    Weekend,
+    Season(Season),
+    Holiday(Holiday),
+}
+
+impl QteMoment {
+    pub fn matches(self, night: bool, day: u32) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Night => night,
+            Self::Weekday => !calendar::is_weekend(day),
+            Self::Weekend => calendar::is_weekend(day),
+            Self::Season(season) => calendar::get_season(day) == season,
+            Self::Holiday(holiday) => calendar::get_holiday(day) == Some(holiday),
+        }
+    }
 }
 
 #[derive(Clone)]
 pub struct QTE {
+    kind: QteKind,
+    /// Name the campaign levels pick it by
+    key: Option<&'static str>,
+    moment: QteMoment,
     text: String,
     effect_choice_1: QteEffect,
     effect_choice_2: QteEffect,
@@ -52,6 +113,9 @@ impl QTE {
         time: f32,
     ) -> Self {
         Self {
+            kind: QteKind::Random,
+            key: None,
+            moment: QteMoment::Always,
             text,
             effect_choice_1,
             effect_choice_2,
@@ -63,6 +127,45 @@ impl QTE {
         }
     }
 
+    pub const fn with_kind(mut self, kind: QteKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub const fn with_moment(mut self, moment: QteMoment) -> Self {
+        self.moment = moment;
+        self
+    }
+
+    pub const fn with_key(mut self, key: &'static str) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    /// Less time to answer, `factor` times less
+    pub fn scale_time(&mut self, factor: f32) {
+        self.time = (self.time / factor).max(MIN_QTE_TIME);
+    }
+
+    /// Both answers weigh `factor` times more
+    pub fn scale_effects(&mut self, factor: f32) {
+        self.effect_choice_1 = self.effect_choice_1.scaled(factor);
+        self.effect_choice_2 = self.effect_choice_2.scaled(factor);
+    }
+
+    pub const fn get_kind(&self) -> QteKind {
+        self.kind
+    }
+
+    pub const fn get_key(&self) -> Option<&'static str> {
+        self.key
+    }
+
+    /// Whether the QTE can be picked at this time of the week or of the year
+    pub fn is_eligible(&self, night: bool, day: u32) -> bool {
+        self.moment.matches(night, day)
+    }
+
     pub fn get_text(&self) -> &str {
         &self.text
     }
@@ -0,0 +1,84 @@
+use macroquad::rand::gen_range;
+
+/// Distance between two desks under which employees can get to know each other
+pub const NEIGHBOUR_DISTANCE: f32 = 230.;
+
+/// Distance under which emotions spread between employees
+pub const CONTAGION_RADIUS: f32 = 230.;
+pub const CONTAGION_RATE: f32 = 0.0001;
+
+pub const GRIEF_HOPE: f32 = 0.3;
+pub const GRIEF_SATISFACTION: f32 = 0.15;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelationshipKind {
+    Friend,
+    Rival,
+}
+
+impl RelationshipKind {
+    /// How much the emotions of the other employee affect this one. A rival
+    /// enjoys the misery of the other and is annoyed by their happiness.
+    pub const fn contagion_factor(self) -> f32 {
+        match self {
+            Self::Friend => 2.,
+            Self::Rival => -1.,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Relationship {
+    pub a: u64,
+    pub b: u64,
+    pub kind: RelationshipKind,
+}
+
+/// Undirected graph of the relationships between employees, by employee id
+#[derive(Default)]
+pub struct Relationships {
+    relationships: Vec<Relationship>,
+}
+
+impl Relationships {
+    pub fn clear(&mut self) {
+        self.relationships.clear();
+    }
+
+    /// Rolls whether two new neighbours become friends, rivals or nothing
+    pub fn meet(&mut self, a: u64, b: u64) {
+        let kind = match gen_range(0, 20) {
+            0..=6 => Some(RelationshipKind::Friend),
+            7..=10 => Some(RelationshipKind::Rival),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            self.relationships.push(Relationship { a, b, kind });
+        }
+    }
+
+    pub fn get(&self, a: u64, b: u64) -> Option<RelationshipKind> {
+        self.relationships
+            .iter()
+            .find(|r| (r.a == a && r.b == b) || (r.a == b && r.b == a))
+            .map(|r| r.kind)
+    }
+
+    /// Every relationship of an employee, as (other employee id, kind)
+    pub fn iter_of(&self, id: u64) -> impl Iterator<Item = (u64, RelationshipKind)> + '_ {
+        self.relationships.iter().filter_map(move |r| {
+            if r.a == id {
+                Some((r.b, r.kind))
+            } else if r.b == id {
+                Some((r.a, r.kind))
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn remove_employee(&mut self, id: u64) {
+        self.relationships.retain(|r| r.a != id && r.b != id);
+    }
+}
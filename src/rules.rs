@@ -0,0 +1,240 @@
+use crate::{file_format, storage};
+
+/// File of the custom rules. Missing values are the ones of the normal
+/// difficulty.
+pub const RULES_FILE: &str = "rules.txt";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RulesPreset {
+    Easy,
+    Normal,
+    Hard,
+    Crunch,
+    /// Read from `RULES_FILE`
+    Custom,
+}
+
+impl RulesPreset {
+    pub const ALL: [Self; 5] = [
+        Self::Easy,
+        Self::Normal,
+        Self::Hard,
+        Self::Crunch,
+        Self::Custom,
+    ];
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Easy => "Facile",
+            Self::Normal => "Normal",
+            Self::Hard => "Difficile",
+            Self::Crunch => "Crunch",
+            Self::Custom => "Personnalisé",
+        }
+    }
+
+    /// Name in the saved files
+    pub const fn get_key(self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Normal => "normal",
+            Self::Hard => "hard",
+            Self::Crunch => "crunch",
+            Self::Custom => "custom",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| preset.get_key() == key)
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Text of the rules, shipped with the game or written by the player
+    fn read(self) -> Option<String> {
+        let text = match self {
+            Self::Easy => include_str!("../assets/rules/easy.txt"),
+            Self::Normal => include_str!("../assets/rules/normal.txt"),
+            Self::Hard => include_str!("../assets/rules/hard.txt"),
+            Self::Crunch => include_str!("../assets/rules/crunch.txt"),
+            Self::Custom => return storage::read(RULES_FILE),
+        };
+
+        Some(text.to_owned())
+    }
+}
+
+/// The balance of a run, chosen before it starts
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rules {
+    pub preset: RulesPreset,
+    /// How much the stats of the employees go down each tick
+    pub decay_rate: f32,
+    /// How much the stat of an activity goes up each tick
+    pub replenish_rate: f32,
+    pub meth_cost: f32,
+    pub rh_cost: f32,
+    /// Seconds before the bonus can be used again
+    pub meth_cooldown: f64,
+    pub rh_cooldown: f64,
+    /// Seconds between two QTEs
+    pub min_qte_period: f32,
+    pub max_qte_period: f32,
+    pub starting_money: f32,
+    /// Multiplier of the decay rate as the run gets harder, not read from the
+    /// files
+    pub decay_factor: f32,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            preset: RulesPreset::Normal,
+            decay_rate: 0.0002,
+            replenish_rate: 0.002,
+            meth_cost: 1000.,
+            rh_cost: 200.,
+            meth_cooldown: 5.,
+            rh_cooldown: 3.,
+            min_qte_period: 10.,
+            max_qte_period: 20.,
+            starting_money: 2000.,
+            decay_factor: 1.,
+        }
+    }
+}
+
+impl Rules {
+    /// Decay rate of the stats at this point of the run
+    pub fn get_decay_rate(&self) -> f32 {
+        self.decay_rate * self.decay_factor
+    }
+
+    pub fn load(preset: RulesPreset) -> Result<Self, String> {
+        let text = preset
+            .read()
+            .ok_or_else(|| format!("{RULES_FILE} introuvable"))?;
+
+        let rules = Self::parse(preset, &text)?;
+        rules.validate()?;
+
+        Ok(rules)
+    }
+
+    /// One "<name> <value>" line per value, the others keep their default
+    pub fn parse(preset: RulesPreset, text: &str) -> Result<Self, String> {
+        let mut rules = Self {
+            preset,
+            ..Self::default()
+        };
+
+        file_format::parse_lines(text, |words| {
+            let [name, value] = words else {
+                return Err(file_format::unknown_line());
+            };
+            let value = file_format::finite(value)?;
+
+            match *name {
+                "decay_rate" => rules.decay_rate = value,
+                "replenish_rate" => rules.replenish_rate = value,
+                "meth_cost" => rules.meth_cost = value,
+                "rh_cost" => rules.rh_cost = value,
+                "meth_cooldown" => rules.meth_cooldown = f64::from(value),
+                "rh_cooldown" => rules.rh_cooldown = f64::from(value),
+                "min_qte_period" => rules.min_qte_period = value,
+                "max_qte_period" => rules.max_qte_period = value,
+                "starting_money" => rules.starting_money = value,
+                _ => return Err(format!("règle inconnue \"{name}\"")),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(rules)
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let values = [
+            self.decay_rate,
+            self.replenish_rate,
+            self.meth_cost,
+            self.rh_cost,
+            self.meth_cooldown as f32,
+            self.rh_cooldown as f32,
+            self.min_qte_period,
+            self.max_qte_period,
+            self.starting_money,
+        ];
+
+        if values.iter().any(|v| *v < 0.) {
+            return Err("Les règles ne peuvent pas être négatives.".to_owned());
+        }
+
+        if self.max_qte_period <= self.min_qte_period {
+            return Err("La période maximale des QTE doit dépasser la minimale.".to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_presets_are_valid() {
+        for preset in RulesPreset::ALL {
+            if preset != RulesPreset::Custom {
+                let rules = Rules::load(preset).unwrap();
+                assert_eq!(rules.preset, preset);
+            }
+        }
+    }
+
+    #[test]
+    fn normal_preset_is_the_default() {
+        assert_eq!(Rules::load(RulesPreset::Normal), Ok(Rules::default()));
+    }
+
+    #[test]
+    fn missing_values_keep_their_default() {
+        let rules = Rules::parse(RulesPreset::Custom, "# Riche\nstarting_money 5000\n").unwrap();
+
+        assert_eq!(
+            rules,
+            Rules {
+                preset: RulesPreset::Custom,
+                starting_money: 5000.,
+                ..Rules::default()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_rule_is_an_error() {
+        assert_eq!(
+            Rules::parse(RulesPreset::Custom, "decay_rate 0.1\nspeed 2"),
+            Err("ligne 2 : règle inconnue \"speed\"".to_owned())
+        );
+        assert!(Rules::parse(RulesPreset::Custom, "decay_rate 0.1 0.2").is_err());
+    }
+
+    #[test]
+    fn negative_values_are_invalid() {
+        let rules = Rules::parse(RulesPreset::Custom, "rh_cost -10").unwrap();
+
+        assert!(rules.validate().is_err());
+    }
+
+    #[test]
+    fn qte_periods_must_be_ordered() {
+        let rules =
+            Rules::parse(RulesPreset::Custom, "min_qte_period 20\nmax_qte_period 10").unwrap();
+
+        assert!(rules.validate().is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{company::Company, history::Fate};
+
+/// Points of the score for each day survived, each money earned, the largest
+/// headcount and each employee who got away
+const DAY_POINTS: f32 = 100.;
+const MONEY_POINTS: f32 = 1.;
+const HEADCOUNT_POINTS: f32 = 250.;
+const FLED_PENALTY: f32 = 150.;
+
+/// What the player did during a run, the rest is read from the company at the end
+#[derive(Default)]
+pub struct RunStats {
+    qte_accepted: u32,
+    qte_refused: u32,
+    meth_count: u32,
+    rh_count: u32,
+    door_closes: u32,
+    /// Days that started with a door closed, by the player or by the intro
+    closed_door_days: u32,
+    peak_headcount: usize,
+    /// Money and headcount at the start of each day
+    money_history: Vec<f32>,
+    headcount_history: Vec<usize>,
+}
+
+impl RunStats {
+    pub const fn record_qte(&mut self, first_choice: bool) {
+        if first_choice {
+            self.qte_accepted += 1;
+        } else {
+            self.qte_refused += 1;
+        }
+    }
+
+    pub const fn record_meth(&mut self) {
+        self.meth_count += 1;
+    }
+
+    pub const fn record_rh(&mut self) {
+        self.rh_count += 1;
+    }
+
+    /// The player closed the door
+    pub const fn record_door_close(&mut self) {
+        self.door_closes += 1;
+    }
+
+    /// Whether a door was closed when the day started
+    pub const fn record_dawn_door(&mut self, closed: bool) {
+        if closed {
+            self.closed_door_days += 1;
+        }
+    }
+
+    pub const fn get_qte_accepted(&self) -> u32 {
+        self.qte_accepted
+    }
+
+    pub const fn get_qte_refused(&self) -> u32 {
+        self.qte_refused
+    }
+
+    pub const fn get_meth_count(&self) -> u32 {
+        self.meth_count
+    }
+
+    pub const fn get_door_closes(&self) -> u32 {
+        self.door_closes
+    }
+
+    pub const fn get_closed_door_days(&self) -> u32 {
+        self.closed_door_days
+    }
+
+    pub fn update_headcount(&mut self, headcount: usize) {
+        self.peak_headcount = self.peak_headcount.max(headcount);
+    }
+
+    pub fn record_day(&mut self, company: &Company) {
+        self.money_history.push(company.get_money());
+        self.headcount_history.push(company.employees_count());
+    }
+}
+
+/// End of run summary shown on the game over screen
+pub struct RunSummary {
+    pub days: u32,
+    pub earned: f32,
+    pub peak_headcount: usize,
+    pub hired: usize,
+    pub died: usize,
+    pub fled: usize,
+    pub jumped: usize,
+    pub burned: usize,
+    pub qte_accepted: u32,
+    pub qte_refused: u32,
+    pub meth_count: u32,
+    pub rh_count: u32,
+    pub money_history: Vec<f32>,
+    pub headcount_history: Vec<f32>,
+    /// Multiplier of the score, for the time survived in endless mode
+    pub score_factor: f32,
+}
+
+impl RunSummary {
+    pub fn new(stats: &RunStats, company: &Company) -> Self {
+        let mut money_history = stats.money_history.clone();
+        money_history.push(company.get_money());
+
+        let mut headcount_history = stats
+            .headcount_history
+            .iter()
+            .map(|&h| h as f32)
+            .collect::<Vec<f32>>();
+        headcount_history.push(company.employees_count() as f32);
+
+        Self {
+            days: company.get_day(),
+            earned: company.get_earned(),
+            peak_headcount: stats.peak_headcount,
+            hired: company.hired_count(),
+            died: company.fate_count(Fate::Died),
+            fled: company.fate_count(Fate::Fled),
+            jumped: company.fate_count(Fate::Jumped),
+            burned: company.fate_count(Fate::Burned),
+            qte_accepted: stats.qte_accepted,
+            qte_refused: stats.qte_refused,
+            meth_count: stats.meth_count,
+            rh_count: stats.rh_count,
+            money_history,
+            headcount_history,
+            score_factor: 1.,
+        }
+    }
+
+    pub const fn with_score_factor(mut self, factor: f32) -> Self {
+        self.score_factor = factor;
+        self
+    }
+
+    pub fn get_score(&self) -> u32 {
+        let score = (self.fled as f32).mul_add(
+            -FLED_PENALTY,
+            (self.peak_headcount as f32).mul_add(
+                HEADCOUNT_POINTS,
+                (self.days as f32).mul_add(DAY_POINTS, self.earned * MONEY_POINTS),
+            ),
+        );
+
+        (score.max(0.) * self.score_factor).round() as u32
+    }
+}
@@ -0,0 +1,163 @@
+use macroquad::{
+    math::{vec2, Rect, Vec2},
+    window::{screen_height, screen_width},
+};
+
+use crate::drawing::{
+    GAME_WINDOW_HEIGHT, GAME_WINDOW_WIDTH, GLOBAL_STAT_HEIGHT, GLOBAL_STAT_WIDTH, INFO_HEIGHT,
+    INFO_WIDTH, OFFICE_HEIGHT, OFFICE_WIDTH, PERSONNAL_STAT_HEIGHT, PERSONNAL_STAT_WIDTH,
+};
+
+/// Space around the panels, relative to the smallest side of the window
+const MARGIN: f32 = 0.01;
+
+/// Width of the left column and height of the top row in landscape
+const SIDE_COLUMN: f32 = 0.28;
+const TOP_ROW: f32 = 0.28;
+
+/// Largest share of the height the top panels take in portrait
+const PORTRAIT_GLOBAL_STAT: f32 = 0.15;
+const PORTRAIT_OFFICE: f32 = 0.4;
+const PORTRAIT_PERSONNAL_STAT: f32 = 0.4;
+
+/// Where the panels are drawn on the screen, in pixels. It is computed from
+/// the size of the window, and used both to draw and to find what was clicked.
+#[derive(Clone, Copy, Debug)]
+pub struct ScreenLayout {
+    pub office: Rect,
+    pub info: Rect,
+    pub global_stat: Rect,
+    pub personnal_stat: Rect,
+    /// Menus and the layout editor, drawn in the main render target
+    pub menu: Rect,
+}
+
+/// Largest rect with the proportions of `size` centered in `area`
+fn fit(area: Rect, size: Vec2) -> Rect {
+    let scale = (area.w / size.x).min(area.h / size.y).max(0.);
+    let fitted = size * scale;
+
+    Rect::new(
+        area.x + (area.w - fitted.x) / 2.,
+        area.y + (area.h - fitted.y) / 2.,
+        fitted.x,
+        fitted.y,
+    )
+}
+
+const fn size(width: u32, height: u32) -> Vec2 {
+    vec2(width as f32, height as f32)
+}
+
+/// Puts the panel under the previous one, as large as the width allows
+fn stack(y: &mut f32, margin: f32, width: f32, max_height: f32, panel: Vec2) -> Rect {
+    let rect = fit(Rect::new(margin, *y, width, max_height), panel);
+    let rect = Rect::new(rect.x, *y, rect.w, rect.h);
+    *y += rect.h + margin;
+    rect
+}
+
+impl ScreenLayout {
+    pub fn new(width: f32, height: f32) -> Self {
+        let menu = fit(
+            Rect::new(0., 0., width, height),
+            size(GAME_WINDOW_WIDTH, GAME_WINDOW_HEIGHT),
+        );
+
+        if width >= height {
+            Self::landscape(width, height, menu)
+        } else {
+            Self::portrait(width, height, menu)
+        }
+    }
+
+    /// Layout of the current window
+    pub fn current() -> Self {
+        Self::new(screen_width(), screen_height())
+    }
+
+    /// Stats on the left, global stats on top and the office in the corner
+    fn landscape(width: f32, height: f32, menu: Rect) -> Self {
+        let margin = MARGIN * width.min(height);
+        let side = SIDE_COLUMN * width;
+        let top = TOP_ROW * height;
+        let right = 3.0f32.mul_add(-margin, width - side);
+        let bottom = 3.0f32.mul_add(-margin, height - top);
+
+        Self {
+            personnal_stat: fit(
+                Rect::new(margin, margin, side, top),
+                size(PERSONNAL_STAT_WIDTH, PERSONNAL_STAT_HEIGHT),
+            ),
+            global_stat: fit(
+                Rect::new(2.0f32.mul_add(margin, side), margin, right, top),
+                size(GLOBAL_STAT_WIDTH, GLOBAL_STAT_HEIGHT),
+            ),
+            info: fit(
+                Rect::new(margin, 2.0f32.mul_add(margin, top), side, bottom),
+                size(INFO_WIDTH, INFO_HEIGHT),
+            ),
+            office: fit(
+                Rect::new(
+                    2.0f32.mul_add(margin, side),
+                    2.0f32.mul_add(margin, top),
+                    right,
+                    bottom,
+                ),
+                size(OFFICE_WIDTH, OFFICE_HEIGHT),
+            ),
+            menu,
+        }
+    }
+
+    /// Every panel stacked, the office right under the global stats
+    fn portrait(width: f32, height: f32, menu: Rect) -> Self {
+        let margin = MARGIN * width.min(height);
+        let inner = 2.0f32.mul_add(-margin, width);
+        let mut y = margin;
+
+        let global_stat = stack(
+            &mut y,
+            margin,
+            inner,
+            PORTRAIT_GLOBAL_STAT * height,
+            size(GLOBAL_STAT_WIDTH, GLOBAL_STAT_HEIGHT),
+        );
+        let office = stack(
+            &mut y,
+            margin,
+            inner,
+            PORTRAIT_OFFICE * height,
+            size(OFFICE_WIDTH, OFFICE_HEIGHT),
+        );
+        let remaining = height - y;
+        let personnal_stat = stack(
+            &mut y,
+            margin,
+            inner,
+            PORTRAIT_PERSONNAL_STAT * remaining,
+            size(PERSONNAL_STAT_WIDTH, PERSONNAL_STAT_HEIGHT),
+        );
+        let info_height = height - y - margin;
+        let info = stack(
+            &mut y,
+            margin,
+            inner,
+            info_height,
+            size(INFO_WIDTH, INFO_HEIGHT),
+        );
+
+        Self {
+            office,
+            info,
+            global_stat,
+            personnal_stat,
+            menu,
+        }
+    }
+}
+
+/// Position in a panel of `size` drawn in `rect`, from a position on the screen
+pub fn to_panel(rect: Rect, size: Vec2, screen_pos: Vec2) -> Vec2 {
+    (screen_pos - rect.point()) / rect.size() * size
+}
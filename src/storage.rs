@@ -0,0 +1,60 @@
+/// Reads a file kept between runs. On desktop the path is relative to the
+/// working directory, on web it is a key of the local storage of the browser.
+pub fn read(file: &str) -> Option<String> {
+    backend::read(file)
+}
+
+pub fn write(file: &str, text: &str) -> Result<(), String> {
+    backend::write(file, text)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod backend {
+    use std::fs;
+
+    pub fn read(file: &str) -> Option<String> {
+        fs::read_to_string(file).ok()
+    }
+
+    pub fn write(file: &str, text: &str) -> Result<(), String> {
+        fs::write(file, text).map_err(|e| e.to_string())
+    }
+}
+
+/// The functions of web/storage.js, a miniquad plugin
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    extern "C" {
+        /// Length in bytes of the saved text, -1 if there is none
+        fn storage_length(key: *const u8, key_len: usize) -> i32;
+        fn storage_read(key: *const u8, key_len: usize, buffer: *mut u8, buffer_len: usize);
+        /// 0 if the browser refused to save the text
+        fn storage_write(key: *const u8, key_len: usize, text: *const u8, text_len: usize) -> i32;
+    }
+
+    /// Version 0.1.0 of the plugin, checked by gl.js
+    #[no_mangle]
+    pub extern "C" fn storage_crate_version() -> u32 {
+        1 << 16
+    }
+
+    pub fn read(file: &str) -> Option<String> {
+        let length = unsafe { storage_length(file.as_ptr(), file.len()) };
+        let length = usize::try_from(length).ok()?;
+
+        let mut buffer = vec![0; length];
+        unsafe { storage_read(file.as_ptr(), file.len(), buffer.as_mut_ptr(), length) };
+
+        String::from_utf8(buffer).ok()
+    }
+
+    pub fn write(file: &str, text: &str) -> Result<(), String> {
+        let saved = unsafe { storage_write(file.as_ptr(), file.len(), text.as_ptr(), text.len()) };
+
+        if saved == 0 {
+            return Err("stockage du navigateur plein ou désactivé".to_owned());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use macroquad::math::Vec2;
+
+use crate::{
+    employee::{Computer, EMPLOYEE_SPEED},
+    navigation::{follow_path, NavGrid, ENTRANCE, EXIT},
+};
+
+/// Ticks needed to repair a computer
+pub const REPAIR_TIME: f32 = 600.;
+
+/// Where the technician stands, next to the desk spot
+const WORK_OFFSET: Vec2 = Vec2::new(0., -45.);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TechnicianState {
+    Coming,
+    Repairing,
+    Leaving,
+}
+
+/// Walks to a broken computer, repairs it and leaves. The technician has the
+/// keys, so the door does not stop them.
+pub struct Technician {
+    position: Vec2,
+    rotation: f32,
+    path: VecDeque<Vec2>,
+    computer: Rc<RefCell<Computer>>,
+    state: TechnicianState,
+}
+
+impl Technician {
+    pub fn new(computer: Rc<RefCell<Computer>>, nav: &NavGrid) -> Self {
+        let target = computer.borrow().spot + WORK_OFFSET;
+
+        Self {
+            position: ENTRANCE,
+            rotation: 0.,
+            path: nav
+                .find_path(ENTRANCE, target)
+                .unwrap_or_else(|| vec![target])
+                .into(),
+            computer,
+            state: TechnicianState::Coming,
+        }
+    }
+
+    pub fn tick(&mut self, nav: &NavGrid) {
+        if let Some(rotation) = follow_path(&mut self.position, &mut self.path, EMPLOYEE_SPEED) {
+            self.rotation = rotation;
+        }
+
+        match self.state {
+            TechnicianState::Coming => {
+                if self.path.is_empty() {
+                    self.state = TechnicianState::Repairing;
+                }
+            }
+            TechnicianState::Repairing => {
+                let mut computer = self.computer.borrow_mut();
+                computer.repair_progress += 1. / REPAIR_TIME;
+
+                // Face the computer while working on it
+                let facing = computer.position - self.position;
+                self.rotation = facing.y.atan2(facing.x);
+
+                if computer.repair_progress >= 1. {
+                    computer.broken = false;
+                    computer.being_repaired = false;
+                    computer.repair_progress = 0.;
+
+                    self.path = nav
+                        .find_path(self.position, EXIT)
+                        .unwrap_or_else(|| vec![EXIT])
+                        .into();
+                    self.state = TechnicianState::Leaving;
+                }
+            }
+            TechnicianState::Leaving => (),
+        }
+    }
+
+    /// The technician left the building and can be removed
+    pub fn is_gone(&self) -> bool {
+        self.state == TechnicianState::Leaving && self.path.is_empty()
+    }
+
+    pub const fn get_pos(&self) -> Vec2 {
+        self.position
+    }
+
+    pub const fn get_rotation(&self) -> f32 {
+        self.rotation
+    }
+}
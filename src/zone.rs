@@ -0,0 +1,150 @@
+use macroquad::{
+    color::Color,
+    math::{Rect, Vec2},
+};
+
+use crate::employee::EmployeeAction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ZoneKind {
+    Kitchen,
+    BreakRoom,
+    SleepingPods,
+    PhoneBooth,
+}
+
+impl ZoneKind {
+    pub const ALL: [Self; 4] = [
+        Self::Kitchen,
+        Self::BreakRoom,
+        Self::SleepingPods,
+        Self::PhoneBooth,
+    ];
+
+    /// Zone where the action takes place, if any
+    pub const fn for_action(action: EmployeeAction) -> Option<Self> {
+        match action {
+            EmployeeAction::Eat => Some(Self::Kitchen),
+            EmployeeAction::Break => Some(Self::BreakRoom),
+            EmployeeAction::Sleep => Some(Self::SleepingPods),
+            EmployeeAction::FamilyCall => Some(Self::PhoneBooth),
+            EmployeeAction::None | EmployeeAction::ForcedSleep => None,
+        }
+    }
+
+    pub const fn get_name(self) -> &'static str {
+        match self {
+            Self::Kitchen => "Cuisine",
+            Self::BreakRoom => "Salle de pause",
+            Self::SleepingPods => "Dortoir",
+            Self::PhoneBooth => "Téléphone",
+        }
+    }
+
+    pub const fn get_color(self) -> Color {
+        match self {
+            Self::Kitchen => Color::new(0.55, 0.75, 0.95, 0.5),
+            Self::BreakRoom => Color::new(0.95, 0.75, 0.2, 0.5),
+            Self::SleepingPods => Color::new(0.75, 0.6, 0.95, 0.5),
+            Self::PhoneBooth => Color::new(0.5, 0.85, 0.5, 0.5),
+        }
+    }
+}
+
+/// A place of the office where employees go to do an action, with a limited
+/// number of slots and a queue for the employees waiting for one
+pub struct Zone {
+    kind: ZoneKind,
+    rect: Rect,
+    slots: Vec<Vec2>,
+    occupants: Vec<Option<u64>>,
+    queue_start: Vec2,
+    queue_step: Vec2,
+    queue: Vec<u64>,
+}
+
+impl Zone {
+    pub fn new(
+        kind: ZoneKind,
+        rect: Rect,
+        slots: Vec<Vec2>,
+        queue_start: Vec2,
+        queue_step: Vec2,
+    ) -> Self {
+        Self {
+            kind,
+            rect,
+            occupants: vec![None; slots.len()],
+            slots,
+            queue_start,
+            queue_step,
+            queue: Vec::new(),
+        }
+    }
+
+    pub const fn get_kind(&self) -> ZoneKind {
+        self.kind
+    }
+
+    pub const fn get_rect(&self) -> Rect {
+        self.rect
+    }
+
+    pub const fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn occupied_count(&self) -> usize {
+        self.occupants.iter().flatten().count()
+    }
+
+    pub const fn queue_len(&self) -> usize {
+        self.queue.len()
+    }
+
+    fn contains(&self, id: u64) -> bool {
+        self.occupants.contains(&Some(id)) || self.queue.contains(&id)
+    }
+
+    /// Takes a free slot, or the end of the queue
+    pub fn join(&mut self, id: u64) {
+        if self.contains(id) {
+            return;
+        }
+
+        if let Some(slot) = self.occupants.iter_mut().find(|o| o.is_none()) {
+            *slot = Some(id);
+        } else {
+            self.queue.push(id);
+        }
+    }
+
+    /// Frees the slot of the employee and gives it to the first one in the queue
+    pub fn leave(&mut self, id: u64) {
+        self.queue.retain(|&q| q != id);
+
+        if let Some(slot) = self.occupants.iter_mut().find(|o| **o == Some(id)) {
+            *slot = if self.queue.is_empty() {
+                None
+            } else {
+                Some(self.queue.remove(0))
+            };
+        }
+    }
+
+    pub fn is_using(&self, id: u64) -> bool {
+        self.occupants.contains(&Some(id))
+    }
+
+    /// Where the employee should stand, in a slot or in the queue
+    pub fn position_of(&self, id: u64) -> Option<Vec2> {
+        if let Some(slot) = self.occupants.iter().position(|&o| o == Some(id)) {
+            return Some(self.slots[slot]);
+        }
+
+        self.queue
+            .iter()
+            .position(|&q| q == id)
+            .map(|i| self.queue_start + self.queue_step * i as f32)
+    }
+}
@@ -0,0 +1,32 @@
+"use strict";
+
+// Saved files of the game, kept in the local storage of the browser. See
+// src/storage.rs for the Rust side.
+miniquad_add_plugin({
+  name: "storage",
+  version: "0.1.0",
+  register_plugin: function (importObject) {
+    const encoder = new TextEncoder();
+    const key = (ptr, len) => "upsi4/" + UTF8ToString(ptr, len);
+
+    importObject.env.storage_length = function (key_ptr, key_len) {
+      const text = localStorage.getItem(key(key_ptr, key_len));
+      return text === null ? -1 : encoder.encode(text).length;
+    };
+
+    importObject.env.storage_read = function (key_ptr, key_len, buffer, buffer_len) {
+      const text = localStorage.getItem(key(key_ptr, key_len)) || "";
+      const bytes = encoder.encode(text).subarray(0, buffer_len);
+      new Uint8Array(wasm_memory.buffer, buffer, buffer_len).set(bytes);
+    };
+
+    importObject.env.storage_write = function (key_ptr, key_len, text, text_len) {
+      try {
+        localStorage.setItem(key(key_ptr, key_len), UTF8ToString(text, text_len));
+        return 1;
+      } catch (e) {
+        return 0;
+      }
+    };
+  },
+});